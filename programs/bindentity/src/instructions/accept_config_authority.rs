@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Global, CustomError};
+
+#[derive(Accounts)]
+pub struct AcceptConfigAuthority<'info> {
+    #[account(
+        mut,
+        seeds = ["global".as_bytes()],
+        bump = global.bump,
+        constraint = global.pending_authority.key() == pending_authority.key() @ CustomError::NotPendingAuthority,
+    )]
+    pub global: Account<'info, Global>,
+
+    pub pending_authority: Signer<'info>,
+}
+
+pub fn accept_config_authority_handler(ctx: Context<AcceptConfigAuthority>) -> Result<()> {
+    let global = &mut ctx.accounts.global;
+
+    global.authority = ctx.accounts.pending_authority.key();
+    global.pending_authority = Pubkey::default();
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Offer, Provider},
+    CustomError,
+};
+
+/// Terms the authority agreed to, the offer can be replaced by the offerer until it is accepted.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct AcceptOfferParams {
+    expected_amount: u64,
+    expected_treasury: Pubkey,
+    expected_registration_fee: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: AcceptOfferParams)]
+pub struct AcceptOffer<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "offer".as_bytes(),
+            provider.key().as_ref(),
+            offerer.key().as_ref(),
+        ],
+        bump = offer.bump,
+        has_one = offerer,
+        constraint = offer.amount == params.expected_amount @ CustomError::InvalidOffer,
+        constraint = offer.provider_treasury.key() == params.expected_treasury.key() @ CustomError::InvalidOffer,
+        constraint = offer.registration_fee == params.expected_registration_fee @ CustomError::InvalidOffer,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = provider.validator_count == 0 @ CustomError::SellingNotAllowed,
+        constraint = provider.license_count == 0 @ CustomError::SellingNotAllowed,
+        constraint = provider.flags & 1024 != 1024 @ CustomError::SellingNotAllowed,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: constraint to the offerer of the offer, receives the rent back
+    pub offerer: UncheckedAccount<'info>,
+}
+
+/// The offered amount goes to the authority and the offerer takes over the provider
+/// the same way as `buy_provider`.
+pub fn accept_offer_handler(ctx: Context<AcceptOffer>, _params: AcceptOfferParams) -> Result<()> {
+    let offer = &mut ctx.accounts.offer;
+    let provider = &mut ctx.accounts.provider;
+
+    provider.reset_ownership(
+        &offer.offerer,
+        &offer.provider_treasury,
+        offer.registration_fee,
+    );
+
+    let source_account_info = offer.to_account_info();
+    let authority_account_info = ctx.accounts.authority.to_account_info();
+    let offerer_account_info = ctx.accounts.offerer.to_account_info();
+
+    // the offered amount goes to the authority, the rent back to the offerer
+    let rent = source_account_info
+        .lamports()
+        .checked_sub(offer.amount)
+        .unwrap();
+
+    **authority_account_info.lamports.borrow_mut() = authority_account_info
+        .lamports()
+        .checked_add(offer.amount)
+        .unwrap();
+    **offerer_account_info.lamports.borrow_mut() =
+        offerer_account_info.lamports().checked_add(rent).unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Provider, CustomError};
+
+#[derive(Accounts)]
+pub struct AcceptProviderAuthority<'info> {
+    #[account(
+        mut,
+        constraint = provider.pending_authority.key() == pending_authority.key() @ CustomError::NotPendingAuthority,
+        constraint = provider.flags & 8 != 8 @ CustomError::ProviderListed,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    pub pending_authority: Signer<'info>,
+}
+
+pub fn accept_provider_authority_handler(ctx: Context<AcceptProviderAuthority>) -> Result<()> {
+    let provider = &mut ctx.accounts.provider;
+    let pending_authority = &ctx.accounts.pending_authority;
+
+    if provider.authority.key() != pending_authority.key() {
+        // verification belongs to the previous authority, remove verified flag (4)
+        provider.flags &= 65531;
+        provider.verified_bindie = Pubkey::default();
+
+        // delegates were granted by the previous authority
+        provider.authority_epoch = provider.authority_epoch.wrapping_add(1);
+    }
+
+    provider.authority = pending_authority.key();
+    provider.pending_authority = Pubkey::default();
+
+    Ok(())
+}
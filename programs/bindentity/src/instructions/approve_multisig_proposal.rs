@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Multisig, MultisigProposal},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct ApproveMultisigProposal<'info> {
+    #[account(
+        mut,
+        has_one = multisig,
+        constraint = !proposal.executed @ CustomError::ProposalExecuted,
+        realloc = MultisigProposal::len(multisig.members.len(), &proposal.accounts, &proposal.data),
+        realloc::payer = member,
+        realloc::zero = false,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        constraint = multisig.members.contains(&member.key()) @ CustomError::NotAMultisigMember,
+    )]
+    pub member: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn approve_multisig_proposal_handler(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let member = &ctx.accounts.member;
+
+    // drop approvals of removed members
+    proposal
+        .approvals
+        .retain(|approval| multisig.members.contains(approval));
+
+    if proposal.approvals.contains(&member.key()) {
+        return Err(error!(CustomError::AlreadyApproved));
+    }
+
+    proposal.approvals.push(member.key());
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Provider, ProviderDelegate, SubProviderApproval},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ApproveSubProviderParams {
+    name: String,
+    owner: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ApproveSubProviderParams)]
+pub struct ApproveSubProvider<'info> {
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "sub_provider_approval".as_bytes(),
+            parent.key().as_ref(),
+            params.name.as_bytes(),
+        ],
+        bump,
+        space = SubProviderApproval::len(&params.name),
+    )]
+    pub approval: Account<'info, SubProviderApproval>,
+
+    #[account(
+        mut,
+        constraint = parent.has_role(&authority.key(), delegate.as_deref(), 8) @ CustomError::RoleUnauthorized,
+    )]
+    pub parent: Account<'info, Provider>,
+
+    #[account(
+        constraint = delegate.provider == parent.key(),
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn approve_sub_provider_handler(
+    ctx: Context<ApproveSubProvider>,
+    params: ApproveSubProviderParams,
+) -> Result<()> {
+    let parent = &mut ctx.accounts.parent;
+    let approval = &mut ctx.accounts.approval;
+
+    if !parent.is_parent_of(&params.name) {
+        return Err(error!(CustomError::SubProviderUnauthorized));
+    }
+
+    approval.bump = *ctx.bumps.get("approval").unwrap();
+    approval.version = SubProviderApproval::VERSION;
+    approval.parent = parent.key();
+    approval.owner = params.owner.key();
+    approval.name = params.name;
+    approval.payer = ctx.accounts.authority.key();
+
+    parent.approval_count += 1;
+
+    Ok(())
+}
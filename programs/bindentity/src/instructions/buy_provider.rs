@@ -1,6 +1,7 @@
-use anchor_lang::{prelude::*, system_program};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 
-use crate::{state::Provider, CustomError};
+use crate::{state::Provider, utils::transfer_fee, CustomError};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BuyProviderParams {
@@ -14,6 +15,7 @@ pub struct BuyProvider<'info> {
     #[account(
         mut,
         constraint = (provider.flags & 8 == 8) @ CustomError::SellingNotAllowed,
+        constraint = provider.flags & 1024 != 1024 @ CustomError::SellingNotAllowed,
         constraint = provider.authority.key() == seller.key()
     )]
     provider: Account<'info, Provider>,
@@ -25,6 +27,16 @@ pub struct BuyProvider<'info> {
     #[account(mut)]
     buyer: Signer<'info>,
 
+    /// Buyer's token account in the provider's payment mint, only if the provider is sold for SPL token.
+    #[account(mut)]
+    buyer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Associated token account of the seller, only if the provider is sold for SPL token.
+    #[account(mut)]
+    seller_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    token_program: Option<Program<'info, Token>>,
+
     system_program: Program<'info, System>,
 }
 
@@ -33,24 +45,31 @@ pub fn buy_provider_handler(ctx: Context<BuyProvider>, params: BuyProviderParams
     let seller = &mut ctx.accounts.seller;
     let provider = &mut ctx.accounts.provider;
 
-    // transfer funds from buyer to seller
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: buyer.to_account_info(),
-            to: seller.to_account_info(),
-        },
-    );
+    let token_program = ctx
+        .accounts
+        .token_program
+        .as_ref()
+        .map(|token_program| token_program.to_account_info());
 
-    system_program::transfer(cpi_ctx, provider.selling_price)?;
+    // transfer funds from buyer to seller
+    transfer_fee(
+        &provider.payment_mint,
+        provider.selling_price,
+        &buyer.to_account_info(),
+        &seller.to_account_info(),
+        ctx.accounts
+            .buyer_token_account
+            .as_deref()
+            .zip(ctx.accounts.seller_token_account.as_deref()),
+        &ctx.accounts.system_program.to_account_info(),
+        token_program.as_ref(),
+    )?;
 
-    // reset provider as if newly created, and assign new authority
-    provider.authority = buyer.key();
-    provider.flags = 1;
-    provider.treasury = params.provider_treasury.key();
-    provider.registration_fee = params.registration_fee;
-    provider.validator_count = 0;
-    provider.selling_price = 0;
+    provider.reset_ownership(
+        &buyer.key(),
+        &params.provider_treasury.key(),
+        params.registration_fee,
+    );
 
     Ok(())
 }
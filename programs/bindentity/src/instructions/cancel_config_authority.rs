@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Global;
+
+#[derive(Accounts)]
+pub struct CancelConfigAuthority<'info> {
+    #[account(
+        mut,
+        seeds = ["global".as_bytes()],
+        bump = global.bump
+    )]
+    pub global: Account<'info, Global>,
+
+    #[account(
+        constraint = authority.key() == global.authority.key()
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn cancel_config_authority_handler(ctx: Context<CancelConfigAuthority>) -> Result<()> {
+    let global = &mut ctx.accounts.global;
+
+    global.pending_authority = Pubkey::default();
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Offer;
+
+#[derive(Accounts)]
+pub struct CancelOffer<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "offer".as_bytes(),
+            offer.provider.key().as_ref(),
+            offerer.key().as_ref(),
+        ],
+        bump = offer.bump,
+        has_one = offerer,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    #[account(mut)]
+    pub offerer: Signer<'info>,
+}
+
+/// Withdraws the offered amount and the rent back to the offerer.
+pub fn cancel_offer_handler(ctx: Context<CancelOffer>) -> Result<()> {
+    let source_account_info = ctx.accounts.offer.to_account_info();
+    let dest_account_info = ctx.accounts.offerer.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    Ok(())
+}
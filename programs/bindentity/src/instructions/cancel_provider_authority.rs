@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Provider;
+
+#[derive(Accounts)]
+pub struct CancelProviderAuthority<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn cancel_provider_authority_handler(ctx: Context<CancelProviderAuthority>) -> Result<()> {
+    let provider = &mut ctx.accounts.provider;
+
+    provider.pending_authority = Pubkey::default();
+
+    Ok(())
+}
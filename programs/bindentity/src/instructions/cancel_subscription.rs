@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Subscription;
+
+#[derive(Accounts)]
+pub struct CancelSubscription<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "subscription".as_bytes(),
+            subscription.bindie.as_ref(),
+        ],
+        bump = subscription.bump,
+        has_one = owner,
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+/// Closes the subscription and returns the remaining lamports to the owner,
+/// the bindie stays active until its `paid_until`.
+pub fn cancel_subscription_handler(ctx: Context<CancelSubscription>) -> Result<()> {
+    let source_account_info = ctx.accounts.subscription.to_account_info();
+    let dest_account_info = ctx.accounts.owner.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{License, Validator},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct CloseLicenseValidators<'info> {
+    #[account(
+        mut,
+        has_one = licensee,
+        constraint = !license.is_active(Clock::get()?.unix_timestamp) @ CustomError::InvalidLicense,
+    )]
+    pub license: Account<'info, License>,
+
+    #[account(mut)]
+    /// CHECK: constraint to the licensee, receives the rent of the validators back
+    pub licensee: UncheckedAccount<'info>,
+}
+
+/// Closes a batch of validators of a revoked or expired license, passed as remaining accounts.
+/// Can be called by anyone, the license can be closed by `revoke_license` once none are left.
+pub fn close_license_validators_handler(ctx: Context<CloseLicenseValidators>) -> Result<()> {
+    let license = &mut ctx.accounts.license;
+
+    for validator_info in ctx.remaining_accounts.iter() {
+        let validator = Account::<Validator>::try_from(validator_info)?;
+
+        if validator.license.key() != license.key() {
+            return Err(error!(CustomError::InvalidLicense));
+        }
+
+        let source_account_info = validator.to_account_info();
+        let dest_account_info = ctx.accounts.licensee.to_account_info();
+
+        let dest_starting_lamports = dest_account_info.lamports();
+        **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+            .checked_add(source_account_info.lamports())
+            .unwrap();
+        **source_account_info.lamports.borrow_mut() = 0;
+
+        let mut source_data = source_account_info.data.borrow_mut();
+        source_data.fill(0);
+
+        license.validator_count -= 1;
+    }
+
+    Ok(())
+}
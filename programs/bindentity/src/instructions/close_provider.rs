@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{self, Token, TokenAccount},
+};
+
+use crate::{
+    state::{FeeVault, Global, Provider, ProviderMetadata, Tombstone},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct CloseProvider<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = provider.validator_count == 0 @ CustomError::ClosingNotAllowed,
+        constraint = provider.flags & 8 != 8 @ CustomError::ClosingNotAllowed,
+        constraint = provider.live_count == 0 @ CustomError::ClosingNotAllowed,
+        constraint = provider.delegate_count == 0 @ CustomError::ClosingNotAllowed,
+        constraint = provider.license_count == 0 @ CustomError::ClosingNotAllowed,
+        constraint = provider.coupon_count == 0 @ CustomError::ClosingNotAllowed,
+        constraint = provider.approval_count == 0 @ CustomError::ClosingNotAllowed,
+        constraint = provider.child_count == 0 @ CustomError::ClosingNotAllowed,
+        // revenue split (64), prerequisites (128), access list (256) and pricing rules (2048)
+        constraint = provider.flags & 2496 == 0 @ CustomError::ClosingNotAllowed,
+        // migrated providers (4096) may have live bindies which were never counted
+        constraint = provider.flags & 4096 != 4096 @ CustomError::ClosingNotAllowed,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    /// Parent provider, required if the provider is a sub-namespace.
+    #[account(mut)]
+    pub parent: Option<Box<Account<'info, Provider>>>,
+
+    #[account(
+        mut,
+        seeds = [
+            "provider_metadata".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = provider_metadata.bump,
+    )]
+    pub provider_metadata: Option<Account<'info, ProviderMetadata>>,
+
+    #[account(
+        mut,
+        seeds = [
+            "vault".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = provider_vault.bump,
+    )]
+    pub provider_vault: Account<'info, FeeVault>,
+
+    /// Associated token account of the provider vault, only if the provider is paid in SPL token.
+    /// It should be emptied beforehand through `withdraw_provider_fees`.
+    #[account(mut)]
+    pub provider_vault_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "tombstone".as_bytes(),
+            provider.name.as_bytes(),
+        ],
+        bump,
+        space = Tombstone::len(),
+    )]
+    pub tombstone: Account<'info, Tombstone>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = ["global".as_bytes()],
+        bump = global.bump
+    )]
+    pub global: Box<Account<'info, Global>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Closes the provider (and its metadata and vault) and returns the rent to the authority.
+/// Every other account derived from the provider should be closed beforehand, so that none of them
+/// carries over to a provider re-created with the same name. A tombstone is left behind so that the name can only be re-registered by the same authority
+/// until the cooldown has passed.
+pub fn close_provider_handler(ctx: Context<CloseProvider>) -> Result<()> {
+    let authority = &mut ctx.accounts.authority;
+    let provider = &mut ctx.accounts.provider;
+    let tombstone = &mut ctx.accounts.tombstone;
+
+    // a closed sub-namespace is no longer counted by its parent
+    if provider.parent.key() != Pubkey::default() {
+        match ctx.accounts.parent.as_mut() {
+            Some(parent) if parent.key() == provider.parent.key() => {
+                parent.child_count = parent.child_count.saturating_sub(1);
+            }
+            _ => return Err(error!(CustomError::ClosingNotAllowed)),
+        }
+    }
+
+    if provider.flags & 32 == 32 {
+        match &ctx.accounts.provider_metadata {
+            Some(metadata) => {
+                let source_account_info = metadata.to_account_info();
+                let dest_account_info = authority.to_account_info();
+
+                let dest_starting_lamports = dest_account_info.lamports();
+                **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+                    .checked_add(source_account_info.lamports())
+                    .unwrap();
+                **source_account_info.lamports.borrow_mut() = 0;
+
+                let mut source_data = source_account_info.data.borrow_mut();
+                source_data.fill(0);
+            }
+            None => return Err(error!(CustomError::ClosingNotAllowed)),
+        }
+    }
+
+    if provider.payment_mint.key() != Pubkey::default() {
+        let (token_account, token_program) = match (
+            ctx.accounts.provider_vault_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) {
+            (Some(token_account), Some(token_program)) => (token_account, token_program),
+            _ => return Err(error!(CustomError::InvalidPaymentAccount)),
+        };
+
+        if token_account.key()
+            != get_associated_token_address(
+                &ctx.accounts.provider_vault.key(),
+                &provider.payment_mint,
+            )
+        {
+            return Err(error!(CustomError::InvalidPaymentAccount));
+        }
+
+        if token_account.amount != 0 {
+            return Err(error!(CustomError::ClosingNotAllowed));
+        }
+
+        let provider_key = provider.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            "vault".as_bytes(),
+            provider_key.as_ref(),
+            &[ctx.accounts.provider_vault.bump],
+        ]];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::CloseAccount {
+                account: token_account.to_account_info(),
+                destination: authority.to_account_info(),
+                authority: ctx.accounts.provider_vault.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        token::close_account(cpi_ctx)?;
+    }
+
+    // remaining SOL fees go to the authority
+    let source_account_info = ctx.accounts.provider_vault.to_account_info();
+    let dest_account_info = authority.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    tombstone.bump = *ctx.bumps.get("tombstone").unwrap();
+    tombstone.version = Tombstone::VERSION;
+    tombstone.authority = authority.key();
+    tombstone.closed_at = Clock::get()?.unix_timestamp;
+    tombstone.cooldown = ctx.accounts.global.provider_cooldown;
+
+    let source_account_info = provider.to_account_info();
+    let dest_account_info = authority.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    Ok(())
+}
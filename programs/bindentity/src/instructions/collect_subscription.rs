@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Bindie, FeeVault, Link, Provider, RevenueSplit, Subscription},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct CollectSubscription<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "subscription".as_bytes(),
+            bindie.key().as_ref(),
+        ],
+        bump = subscription.bump,
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    #[account(
+        mut,
+        has_one = provider,
+    )]
+    pub bindie: Account<'info, Bindie>,
+
+    #[account(
+        constraint = link.bindie.key() == bindie.key(),
+    )]
+    pub link: Account<'info, Link>,
+
+    #[account(mut)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        mut,
+        seeds = [
+            "vault".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = provider_vault.bump,
+    )]
+    pub provider_vault: Account<'info, FeeVault>,
+
+    /// Required if the provider has a revenue split (64), its recipients follow as remaining accounts.
+    #[account(
+        seeds = [
+            "revenue_split".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = revenue_split.bump,
+    )]
+    pub revenue_split: Option<Account<'info, RevenueSplit>>,
+}
+
+/// Collects the fee for the next period from the subscription to the provider vault, or across the revenue split,
+/// can be called by anyone once the bindie is due. Only live bindies can be collected for,
+/// so voiding a bindie stops the subscription.
+pub fn collect_subscription_handler(ctx: Context<CollectSubscription>) -> Result<()> {
+    let subscription = &ctx.accounts.subscription;
+    let bindie = &mut ctx.accounts.bindie;
+    let provider = &mut ctx.accounts.provider;
+    let now = Clock::get()?.unix_timestamp;
+
+    if now < bindie.paid_until {
+        return Err(error!(CustomError::SubscriptionNotDue));
+    }
+
+    let source_account_info = subscription.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(source_account_info.data_len());
+
+    if source_account_info
+        .lamports()
+        .saturating_sub(subscription.fee)
+        < rent_exempt
+    {
+        return Err(error!(CustomError::InsufficientSubscriptionBalance));
+    }
+
+    **source_account_info.lamports.borrow_mut() -= subscription.fee;
+
+    // the fee follows the revenue split the same way as the registration fee
+    if provider.flags & 64 == 64 {
+        let revenue_split = match ctx.accounts.revenue_split.as_ref() {
+            Some(revenue_split) => revenue_split,
+            None => return Err(error!(CustomError::InvalidRevenueSplit)),
+        };
+
+        if ctx.remaining_accounts.len() != revenue_split.recipients.len() {
+            return Err(error!(CustomError::InvalidRevenueSplit));
+        }
+
+        let shares = revenue_split.shares(subscription.fee);
+
+        for (i, amount) in shares.into_iter().enumerate() {
+            let recipient = &ctx.remaining_accounts[i];
+
+            if recipient.key() != revenue_split.recipients[i].recipient.key() {
+                return Err(error!(CustomError::InvalidRevenueSplit));
+            }
+
+            **recipient.lamports.borrow_mut() = recipient.lamports().checked_add(amount).unwrap();
+        }
+    } else {
+        let dest_account_info = ctx.accounts.provider_vault.to_account_info();
+
+        **dest_account_info.lamports.borrow_mut() = dest_account_info
+            .lamports()
+            .checked_add(subscription.fee)
+            .unwrap();
+    }
+
+    // a lapsed bindie is renewed from now, the owner does not pay for the lapsed time
+    bindie.paid_until = bindie.paid_until.max(now) + subscription.period;
+    provider.collected_fees = provider.collected_fees.saturating_add(subscription.fee);
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{AccessList, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateAccessListParams {
+    allowlist_root: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateAccessListParams)]
+pub struct CreateAccessList<'info> {
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "access_list".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump,
+        space = AccessList::len(),
+    )]
+    pub access_list: Account<'info, AccessList>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 4) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_access_list_handler(
+    ctx: Context<CreateAccessList>,
+    params: CreateAccessListParams,
+) -> Result<()> {
+    let access_list = &mut ctx.accounts.access_list;
+    let provider = &mut ctx.accounts.provider;
+
+    access_list.bump = *ctx.bumps.get("access_list").unwrap();
+    access_list.version = AccessList::VERSION;
+    access_list.provider = provider.key();
+    access_list.allowlist_root = params.allowlist_root;
+    access_list.allowed_count = 0;
+    access_list.denied_count = 0;
+    provider.flags |= 256;
+
+    Ok(())
+}
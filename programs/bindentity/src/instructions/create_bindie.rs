@@ -1,7 +1,12 @@
-use anchor_lang::{prelude::*, system_program};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 
 use crate::{
-    state::{Bindie, Global, Link, Provider, Validator},
+    state::{
+        AccessList, Bindie, Coupon, FeeEscrow, FeeVault, Global, License, Link, Prerequisites,
+        PricingRules, Provider, RevenueSplit, Validator, WalletEntry,
+    },
+    utils::transfer_fee,
     CustomError,
 };
 
@@ -11,6 +16,11 @@ pub struct CreateBindieParams {
     timestamp: u64,
     encryption_count: u8,
     registration_fee: Option<u64>,
+    max_fee: Option<u64>,
+    integrator_share: Option<u16>,
+    reveal: Option<String>,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+    coupon_code: Option<String>,
 }
 
 #[derive(Accounts)]
@@ -58,6 +68,7 @@ pub struct CreateBindie<'info> {
     pub signer: Signer<'info>,
 
     #[account(
+        mut,
         constraint = validator.provider.key() == provider.key(),
         constraint = validator.flags & 1 == 1 @ CustomError::ValidatorDisabled,
     )]
@@ -65,23 +76,31 @@ pub struct CreateBindie<'info> {
 
     #[account(
         mut,
-        constraint = provider_treasury.key() == provider.treasury.key()
+        seeds = [
+            "vault".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = provider_vault.bump,
     )]
-    /// CHECK: constraint to provider's treasury
-    pub provider_treasury: UncheckedAccount<'info>,
+    pub provider_vault: Box<Account<'info, FeeVault>>,
 
     #[account(
+        mut,
         constraint = provider.flags & 1 == 1 @ CustomError::ProviderDisabled,
         constraint = provider.flags & 2 == 2 @ CustomError::ProviderUnpublished,
+        constraint = provider.flags & 512 != 512 @ CustomError::ProviderPaused,
     )]
     pub provider: Box<Account<'info, Provider>>,
 
     #[account(
         mut,
-        constraint = treasury.key() == global.treasury.key()
+        seeds = [
+            "vault".as_bytes(),
+            global.key().as_ref(),
+        ],
+        bump = vault.bump,
     )]
-    /// CHECK: constraint to global treasury
-    pub treasury: UncheckedAccount<'info>,
+    pub vault: Box<Account<'info, FeeVault>>,
 
     #[account(
         seeds = ["global".as_bytes()],
@@ -89,57 +108,530 @@ pub struct CreateBindie<'info> {
     )]
     pub global: Box<Account<'info, Global>>,
 
+    /// Owner's token account in the global payment mint, only if the service fee is paid in SPL token.
+    #[account(mut)]
+    pub owner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Associated token account of the protocol vault, only if the service fee is paid in SPL token.
+    #[account(mut)]
+    pub vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Owner's token account in the provider's payment mint, only if the registration fee is paid in SPL token.
+    #[account(mut)]
+    pub owner_provider_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Associated token account of the provider vault, only if the registration fee is paid in SPL token.
+    #[account(mut)]
+    pub provider_vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Wallet of the frontend which brought the owner, receives `integrator_share` of the service fee.
+    /// It co-signs the registration, so that the share cannot be claimed for another wallet.
+    #[account(
+        mut,
+        constraint = integrator.key() != owner.key() @ CustomError::InvalidIntegratorShare,
+    )]
+    pub integrator: Option<Signer<'info>>,
+
+    /// Associated token account of the integrator, only if the service fee is paid in SPL token.
+    #[account(mut)]
+    pub integrator_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Required if the provider has a refund window and only then, holds the registration fee until the window passes.
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            "escrow".as_bytes(),
+            bindie.key().as_ref(),
+        ],
+        bump,
+        space = FeeEscrow::len(),
+    )]
+    pub escrow: Option<Box<Account<'info, FeeEscrow>>>,
+
+    #[account(
+        seeds = [
+            "pricing_rules".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = pricing_rules.bump,
+    )]
+    pub pricing_rules: Option<Box<Account<'info, PricingRules>>>,
+
+    #[account(
+        mut,
+        has_one = provider,
+    )]
+    pub coupon: Option<Box<Account<'info, Coupon>>>,
+
+    /// Owner's bindie from another provider, only if claiming a holder discount.
+    pub holder_bindie: Option<Box<Account<'info, Bindie>>>,
+
+    /// Link of the holder bindie, proving that it is not void.
+    pub holder_link: Option<Box<Account<'info, Link>>>,
+
+    /// Owner's void bindie of the same data, only if claiming a renewal discount.
+    pub previous_bindie: Option<Box<Account<'info, Bindie>>>,
+
+    /// Required if the provider has a revenue split (64).
+    #[account(
+        seeds = [
+            "revenue_split".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = revenue_split.bump,
+    )]
+    pub revenue_split: Option<Box<Account<'info, RevenueSplit>>>,
+
+    /// Required if the provider has prerequisites (128).
+    #[account(
+        seeds = [
+            "prerequisites".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = prerequisites.bump,
+    )]
+    pub prerequisites: Option<Box<Account<'info, Prerequisites>>>,
+
+    /// Required if the provider has an access list (256).
+    #[account(
+        seeds = [
+            "access_list".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = access_list.bump,
+    )]
+    pub access_list: Option<Box<Account<'info, AccessList>>>,
+
+    /// CHECK: wallet entry of the owner, required with the access list even if it does not exist
+    #[account(
+        seeds = [
+            "wallet_entry".as_bytes(),
+            provider.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub wallet_entry: Option<UncheckedAccount<'info>>,
+
+    /// Required if the validator belongs to a license.
+    #[account(
+        mut,
+        has_one = provider,
+    )]
+    pub license: Option<Box<Account<'info, License>>>,
+
+    #[account(mut)]
+    /// CHECK: treasury of the license, checked against `license.treasury`
+    pub license_treasury: Option<UncheckedAccount<'info>>,
+
+    /// Associated token account of the license treasury, only if the registration fee is paid in SPL token.
+    #[account(mut)]
+    pub license_treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn create_bindie_handler(ctx: Context<CreateBindie>, params: CreateBindieParams) -> Result<()> {
+/// If the provider has prerequisites, the owner's bindie and link of each required provider are passed first
+/// as remaining accounts, in pairs and in the order of the prerequisites.
+///
+/// Bindies issued through a license validator pay the license treasury, less the provider's share.
+/// Otherwise, if the provider has a revenue split, the recipients follow in the remaining accounts in the order of the split,
+/// and then their associated token accounts when the registration fee is paid in SPL token.
+pub fn create_bindie_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBindie<'info>>,
+    params: CreateBindieParams,
+) -> Result<()> {
     let bindie = &mut ctx.accounts.bindie;
     let link = &mut ctx.accounts.link;
     let owner = &mut ctx.accounts.owner;
-    let provider = &ctx.accounts.provider;
-    let validator = &ctx.accounts.validator;
-
-    // pay service fee
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: owner.to_account_info(),
-            to: ctx.accounts.treasury.to_account_info(),
-        },
-    );
-
-    system_program::transfer(cpi_ctx, ctx.accounts.global.service_fee)?;
-
-    // pay registration fee
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: owner.to_account_info(),
-            to: ctx.accounts.provider_treasury.to_account_info(),
-        },
-    );
-
-    system_program::transfer(
-        cpi_ctx,
-        if validator.flags & 2 == 2 && params.registration_fee.is_some() {
-            params.registration_fee.unwrap()
+    let provider = &mut ctx.accounts.provider;
+    let validator = &mut ctx.accounts.validator;
+
+    // bounds the damage of a leaked validator key
+    let slot = Clock::get()?.slot;
+
+    if !provider.rate_limit.record(slot) || !validator.rate_limit.record(slot) {
+        return Err(error!(CustomError::RateLimitExceeded));
+    }
+
+    // a denied entry always blocks the owner, an allowed entry or merkle proof is needed if the list is restricted
+    if provider.flags & 256 == 256 {
+        let (access_list, wallet_entry) = match (
+            ctx.accounts.access_list.as_ref(),
+            ctx.accounts.wallet_entry.as_ref(),
+        ) {
+            (Some(access_list), Some(wallet_entry)) => (access_list, wallet_entry),
+            _ => return Err(error!(CustomError::WalletNotAllowed)),
+        };
+
+        let is_allowed = if wallet_entry.data_is_empty() {
+            !access_list.is_restricted()
+                || params
+                    .allowlist_proof
+                    .as_ref()
+                    .is_some_and(|proof| access_list.is_in_allowlist(&owner.key(), proof))
         } else {
-            provider.registration_fee
-        },
+            Account::<WalletEntry>::try_from(wallet_entry)?.allowed
+        };
+
+        if !is_allowed {
+            return Err(error!(CustomError::WalletNotAllowed));
+        }
+    }
+
+    // the owner must hold a live bindie of every prerequisite provider
+    let prerequisite_accounts = if provider.flags & 128 == 128 {
+        let prerequisites = match ctx.accounts.prerequisites.as_ref() {
+            Some(prerequisites) => prerequisites,
+            None => return Err(error!(CustomError::MissingPrerequisite)),
+        };
+
+        if ctx.remaining_accounts.len() < prerequisites.providers.len() * 2 {
+            return Err(error!(CustomError::MissingPrerequisite));
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+
+        for (i, required) in prerequisites.providers.iter().enumerate() {
+            let prerequisite_bindie = Account::<Bindie>::try_from(&ctx.remaining_accounts[i * 2])?;
+            let prerequisite_link = Account::<Link>::try_from(&ctx.remaining_accounts[i * 2 + 1])?;
+
+            if prerequisite_bindie.provider.key() != required.key()
+                || prerequisite_bindie.owner.key() != owner.key()
+                || prerequisite_link.bindie.key() != prerequisite_bindie.key()
+                || !prerequisite_bindie.is_active(now)
+            {
+                return Err(error!(CustomError::MissingPrerequisite));
+            }
+        }
+
+        prerequisites.providers.len() * 2
+    } else {
+        0
+    };
+
+    let token_program = ctx
+        .accounts
+        .token_program
+        .as_ref()
+        .map(|token_program| token_program.to_account_info());
+
+    // pay service fee, the integrator's share is taken out of it
+    let integrator_fee = match (ctx.accounts.integrator.as_ref(), params.integrator_share) {
+        (Some(integrator), Some(integrator_share)) => {
+            if integrator_share > ctx.accounts.global.max_integrator_share {
+                return Err(error!(CustomError::InvalidIntegratorShare));
+            }
+
+            let integrator_fee =
+                (ctx.accounts.global.service_fee as u128 * integrator_share as u128 / 10000) as u64;
+
+            transfer_fee(
+                &ctx.accounts.global.payment_mint,
+                integrator_fee,
+                &owner.to_account_info(),
+                &integrator.to_account_info(),
+                ctx.accounts
+                    .owner_token_account
+                    .as_deref()
+                    .zip(ctx.accounts.integrator_token_account.as_deref()),
+                &ctx.accounts.system_program.to_account_info(),
+                token_program.as_ref(),
+            )?;
+
+            integrator_fee
+        }
+        _ => 0,
+    };
+
+    transfer_fee(
+        &ctx.accounts.global.payment_mint,
+        ctx.accounts.global.service_fee - integrator_fee,
+        &owner.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts
+            .owner_token_account
+            .as_deref()
+            .zip(ctx.accounts.vault_token_account.as_deref()),
+        &ctx.accounts.system_program.to_account_info(),
+        token_program.as_ref(),
     )?;
 
+    // the data is validated against the provider's schema whenever its plaintext is known
+    let plaintext = if params.encryption_count == 0 {
+        Some(&params.data)
+    } else {
+        match params.reveal.as_ref() {
+            Some(reveal) => {
+                if !Bindie::is_reveal_of(reveal, &params.data, params.encryption_count) {
+                    return Err(error!(CustomError::InvalidDataReveal));
+                }
+
+                Some(reveal)
+            }
+            None => None,
+        }
+    };
+
+    if let Some(plaintext) = plaintext {
+        if !provider.data_schema.matches(plaintext) {
+            return Err(error!(CustomError::DataSchemaMismatch));
+        }
+    }
+
+    let data = if params.encryption_count == 0 {
+        params.data
+    } else {
+        Bindie::data_hash(&provider.name, &params.data)
+    };
+
+    // validators of a license charge the license's fee, of which the provider takes its share
+    let license = if validator.license.key() != Pubkey::default() {
+        match ctx.accounts.license.as_mut() {
+            Some(license)
+                if license.key() == validator.license.key()
+                    && license.is_active(Clock::get()?.unix_timestamp) =>
+            {
+                Some(license)
+            }
+            _ => return Err(error!(CustomError::InvalidLicense)),
+        }
+    } else {
+        None
+    };
+
+    // pay registration fee, less the largest applicable discount
+    let registration_fee = if validator.flags & 2 == 2 && params.registration_fee.is_some() {
+        let registration_fee = params.registration_fee.unwrap();
+
+        if registration_fee < provider.min_custom_fee || registration_fee > provider.max_custom_fee
+        {
+            return Err(error!(CustomError::CustomFeeOutOfBounds));
+        }
+
+        registration_fee
+    } else {
+        match license.as_ref() {
+            Some(license) => license.registration_fee,
+            None => provider.registration_fee,
+        }
+    };
+
+    let mut discount = 0;
+
+    if let Some(coupon) = ctx.accounts.coupon.as_mut() {
+        let is_code = params
+            .coupon_code
+            .as_ref()
+            .is_some_and(|code| Coupon::hash_code(&provider.key(), code) == coupon.code_hash);
+
+        if !is_code {
+            return Err(error!(CustomError::InvalidCouponCode));
+        }
+
+        if !coupon.is_redeemable(Clock::get()?.unix_timestamp) {
+            return Err(error!(CustomError::CouponUnavailable));
+        }
+
+        coupon.redemption_count += 1;
+        discount = coupon.discount;
+    }
+
+    if let Some(pricing_rules) = ctx.accounts.pricing_rules.as_ref() {
+        if let Some(holder_bindie) = ctx.accounts.holder_bindie.as_ref() {
+            match ctx.accounts.holder_link.as_ref() {
+                Some(holder_link)
+                    if holder_link.bindie.key() == holder_bindie.key()
+                        && holder_bindie.owner.key() == owner.key()
+                        && holder_bindie.is_active(Clock::get()?.unix_timestamp) =>
+                {
+                    discount = discount.max(pricing_rules.holder_discount(&holder_bindie.provider));
+                }
+                _ => return Err(error!(CustomError::InvalidPricingAccount)),
+            }
+        }
+
+        // the link of the previous bindie is being re-created, so it is guaranteed to be void
+        if let Some(previous_bindie) = ctx.accounts.previous_bindie.as_ref() {
+            if previous_bindie.owner.key() != owner.key()
+                || previous_bindie.provider.key() != provider.key()
+                || previous_bindie.data != data
+            {
+                return Err(error!(CustomError::InvalidPricingAccount));
+            }
+
+            discount = discount.max(pricing_rules.renewal_discount);
+        }
+    }
+
+    let registration_fee = PricingRules::discounted(registration_fee, discount);
+
+    // the owner sets the most they are willing to pay, in case the validator charges more than expected
+    if let Some(max_fee) = params.max_fee {
+        if registration_fee > max_fee {
+            return Err(error!(CustomError::FeeExceedsMaximum));
+        }
+    }
+
+    // the fee is only escrowed if it goes to the provider vault during a refund window,
+    // the owner would otherwise pay the rent of an escrow which is never settled
+    let is_escrowed = license.is_none() && provider.flags & 64 != 64 && provider.refund_window > 0;
+
+    if ctx.accounts.escrow.is_some() != is_escrowed {
+        return Err(error!(CustomError::MissingEscrowAccount));
+    }
+
+    if let Some(license) = license {
+        let license_treasury = match ctx.accounts.license_treasury.as_ref() {
+            Some(license_treasury) if license_treasury.key() == license.treasury.key() => {
+                license_treasury
+            }
+            _ => return Err(error!(CustomError::InvalidLicense)),
+        };
+
+        let parent_fee = (registration_fee as u128 * license.parent_share as u128 / 10000) as u64;
+
+        transfer_fee(
+            &provider.payment_mint,
+            parent_fee,
+            &owner.to_account_info(),
+            &ctx.accounts.provider_vault.to_account_info(),
+            ctx.accounts
+                .owner_provider_token_account
+                .as_deref()
+                .zip(ctx.accounts.provider_vault_token_account.as_deref()),
+            &ctx.accounts.system_program.to_account_info(),
+            token_program.as_ref(),
+        )?;
+
+        transfer_fee(
+            &provider.payment_mint,
+            registration_fee - parent_fee,
+            &owner.to_account_info(),
+            &license_treasury.to_account_info(),
+            ctx.accounts
+                .owner_provider_token_account
+                .as_deref()
+                .zip(ctx.accounts.license_treasury_token_account.as_deref()),
+            &ctx.accounts.system_program.to_account_info(),
+            token_program.as_ref(),
+        )?;
+
+        license.issued_count += 1;
+    } else if provider.flags & 64 == 64 {
+        let revenue_split = match ctx.accounts.revenue_split.as_ref() {
+            Some(revenue_split) => revenue_split,
+            None => return Err(error!(CustomError::InvalidRevenueSplit)),
+        };
+
+        let recipient_count = revenue_split.recipients.len();
+        let is_native = provider.payment_mint.key() == Pubkey::default();
+
+        let expected_accounts = if is_native {
+            recipient_count
+        } else {
+            recipient_count * 2
+        };
+
+        let split_accounts = &ctx.remaining_accounts[prerequisite_accounts..];
+
+        if split_accounts.len() != expected_accounts {
+            return Err(error!(CustomError::InvalidRevenueSplit));
+        }
+
+        let shares = revenue_split.shares(registration_fee);
+
+        for (i, amount) in shares.into_iter().enumerate() {
+            let recipient = &split_accounts[i];
+
+            if recipient.key() != revenue_split.recipients[i].recipient.key() {
+                return Err(error!(CustomError::InvalidRevenueSplit));
+            }
+
+            let recipient_token_account = if is_native {
+                None
+            } else {
+                Some(Account::<TokenAccount>::try_from(
+                    &split_accounts[recipient_count + i],
+                )?)
+            };
+
+            transfer_fee(
+                &provider.payment_mint,
+                amount,
+                &owner.to_account_info(),
+                recipient,
+                ctx.accounts
+                    .owner_provider_token_account
+                    .as_deref()
+                    .zip(recipient_token_account.as_ref()),
+                &ctx.accounts.system_program.to_account_info(),
+                token_program.as_ref(),
+            )?;
+        }
+    } else if provider.refund_window > 0 {
+        let escrow = match ctx.accounts.escrow.as_mut() {
+            Some(escrow) => escrow,
+            None => return Err(error!(CustomError::MissingEscrowAccount)),
+        };
+
+        transfer_fee(
+            &provider.payment_mint,
+            registration_fee,
+            &owner.to_account_info(),
+            &escrow.to_account_info(),
+            None,
+            &ctx.accounts.system_program.to_account_info(),
+            token_program.as_ref(),
+        )?;
+
+        escrow.bump = *ctx.bumps.get("escrow").unwrap();
+        escrow.version = FeeEscrow::VERSION;
+        escrow.bindie = bindie.key();
+        escrow.provider = provider.key();
+        escrow.owner = owner.key();
+        escrow.amount = registration_fee;
+        escrow.release_at = Clock::get()?.unix_timestamp + provider.refund_window;
+        escrow.refund_share = provider.refund_share;
+    } else {
+        transfer_fee(
+            &provider.payment_mint,
+            registration_fee,
+            &owner.to_account_info(),
+            &ctx.accounts.provider_vault.to_account_info(),
+            ctx.accounts
+                .owner_provider_token_account
+                .as_deref()
+                .zip(ctx.accounts.provider_vault_token_account.as_deref()),
+            &ctx.accounts.system_program.to_account_info(),
+            token_program.as_ref(),
+        )?;
+    }
+
+    provider.issued_count += 1;
+    provider.live_count += 1;
+    provider.collected_fees = provider.collected_fees.saturating_add(registration_fee);
+    validator.issued_count += 1;
+
     link.bump = *ctx.bumps.get("link").unwrap();
+    link.version = Link::VERSION;
     link.bindie = bindie.key();
 
     bindie.bump = *ctx.bumps.get("bindie").unwrap();
+    bindie.version = Bindie::VERSION;
     bindie.owner = owner.key();
-    bindie.provider = ctx.accounts.provider.key();
+    bindie.provider = provider.key();
     bindie.timestamp = params.timestamp;
-    bindie.data = if params.encryption_count == 0 {
-        params.data
+    bindie.parent = provider.parent;
+    bindie.paid_until = if provider.subscription_period > 0 {
+        Clock::get()?.unix_timestamp + provider.subscription_period
     } else {
-        Bindie::data_hash(&provider.name, &params.data)
+        0
     };
+    bindie.data = data;
 
     Ok(())
 }
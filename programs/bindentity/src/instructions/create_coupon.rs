@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Coupon, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateCouponParams {
+    code_hash: [u8; 32],
+    discount: u16,
+    max_redemptions: u32,
+    expires_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateCouponParams)]
+pub struct CreateCoupon<'info> {
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "coupon".as_bytes(),
+            provider.key().as_ref(),
+            params.code_hash.as_ref(),
+        ],
+        bump,
+        space = Coupon::len(),
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 1) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_coupon_handler(ctx: Context<CreateCoupon>, params: CreateCouponParams) -> Result<()> {
+    let coupon = &mut ctx.accounts.coupon;
+
+    if params.discount > 10000 {
+        return Err(error!(CustomError::InvalidPricingRules));
+    }
+
+    coupon.bump = *ctx.bumps.get("coupon").unwrap();
+    coupon.version = Coupon::VERSION;
+    coupon.provider = ctx.accounts.provider.key();
+    coupon.discount = params.discount;
+    coupon.max_redemptions = params.max_redemptions;
+    coupon.redemption_count = 0;
+    coupon.expires_at = params.expires_at;
+    coupon.code_hash = params.code_hash;
+
+    ctx.accounts.provider.coupon_count += 1;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{License, Provider, RateLimit, Validator},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateLicenseValidatorParams {
+    enabled: bool,
+    signer: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateLicenseValidatorParams)]
+pub struct CreateLicenseValidator<'info> {
+    #[account(
+        init,
+        payer = licensee,
+        seeds = [
+            "validator".as_bytes(),
+            provider.key().as_ref(),
+            params.signer.key().as_ref(),
+        ],
+        bump,
+        space = Validator::len(),
+    )]
+    pub validator: Account<'info, Validator>,
+
+    #[account(
+        mut,
+        has_one = licensee,
+        has_one = provider,
+        constraint = license.is_active(Clock::get()?.unix_timestamp) @ CustomError::InvalidLicense,
+    )]
+    pub license: Account<'info, License>,
+
+    #[account(
+        constraint = provider.flags & 512 != 512 @ CustomError::ProviderPaused,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut)]
+    pub licensee: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_license_validator_handler(
+    ctx: Context<CreateLicenseValidator>,
+    params: CreateLicenseValidatorParams,
+) -> Result<()> {
+    let license = &mut ctx.accounts.license;
+    let validator = &mut ctx.accounts.validator;
+
+    validator.bump = *ctx.bumps.get("validator").unwrap();
+    validator.version = Validator::VERSION;
+    validator.flags = if params.enabled { 1 } else { 0 };
+    validator.signer = params.signer.key();
+    validator.provider = ctx.accounts.provider.key();
+    validator.issued_count = 0;
+    validator.voided_count = 0;
+    validator.rate_limit = RateLimit::default();
+    validator.license = license.key();
+    license.validator_count += 1;
+
+    Ok(())
+}
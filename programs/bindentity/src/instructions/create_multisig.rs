@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Multisig, CustomError};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateMultisigParams {
+    members: Vec<Pubkey>,
+    threshold: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateMultisigParams)]
+pub struct CreateMultisig<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            "multisig".as_bytes(),
+            create_key.key().as_ref(),
+        ],
+        bump,
+        space = Multisig::len(&params.members),
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        seeds = [
+            "multisig_signer".as_bytes(),
+            multisig.key().as_ref(),
+        ],
+        bump,
+    )]
+    /// CHECK: system owned PDA which signs on behalf of the multisig
+    pub multisig_signer: UncheckedAccount<'info>,
+
+    pub create_key: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_multisig_handler(
+    ctx: Context<CreateMultisig>,
+    params: CreateMultisigParams,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+
+    if !Multisig::is_valid(&params.members, params.threshold) {
+        return Err(error!(CustomError::InvalidMultisig));
+    }
+
+    multisig.bump = *ctx.bumps.get("multisig").unwrap();
+    multisig.version = Multisig::VERSION;
+    multisig.signer_bump = *ctx.bumps.get("multisig_signer").unwrap();
+    multisig.create_key = ctx.accounts.create_key.key();
+    multisig.threshold = params.threshold;
+    multisig.proposal_count = 0;
+    multisig.members = params.members;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Multisig, MultisigProposal, ProposalAccount},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateMultisigProposalParams {
+    accounts: Vec<ProposalAccount>,
+    data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateMultisigProposalParams)]
+pub struct CreateMultisigProposal<'info> {
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [
+            "multisig_proposal".as_bytes(),
+            multisig.key().as_ref(),
+            multisig.proposal_count.to_string().as_bytes(),
+        ],
+        bump,
+        space = MultisigProposal::len(multisig.members.len(), &params.accounts, &params.data),
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    #[account(mut)]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        constraint = multisig.members.contains(&proposer.key()) @ CustomError::NotAMultisigMember,
+    )]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Proposes a Bindentity instruction to be signed by the multisig, approved by the proposer.
+pub fn create_multisig_proposal_handler(
+    ctx: Context<CreateMultisigProposal>,
+    params: CreateMultisigProposalParams,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.bump = *ctx.bumps.get("proposal").unwrap();
+    proposal.version = MultisigProposal::VERSION;
+    proposal.multisig = multisig.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.index = multisig.proposal_count;
+    proposal.executed = false;
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.accounts = params.accounts;
+    proposal.data = params.data;
+
+    multisig.proposal_count += 1;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Prerequisites, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreatePrerequisitesParams {
+    providers: Vec<Pubkey>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreatePrerequisitesParams)]
+pub struct CreatePrerequisites<'info> {
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "prerequisites".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump,
+        space = Prerequisites::len(&params.providers),
+    )]
+    pub prerequisites: Account<'info, Prerequisites>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 4) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_prerequisites_handler(
+    ctx: Context<CreatePrerequisites>,
+    params: CreatePrerequisitesParams,
+) -> Result<()> {
+    let prerequisites = &mut ctx.accounts.prerequisites;
+    let provider = &mut ctx.accounts.provider;
+
+    if !Prerequisites::is_valid(&provider.key(), &params.providers) {
+        return Err(error!(CustomError::InvalidPrerequisites));
+    }
+
+    prerequisites.bump = *ctx.bumps.get("prerequisites").unwrap();
+    prerequisites.version = Prerequisites::VERSION;
+    prerequisites.provider = provider.key();
+    prerequisites.providers = params.providers;
+    provider.flags |= 128;
+
+    Ok(())
+}
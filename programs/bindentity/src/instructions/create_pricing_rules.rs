@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{HolderDiscount, PricingRules, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreatePricingRulesParams {
+    renewal_discount: u16,
+    holder_discounts: Vec<HolderDiscount>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreatePricingRulesParams)]
+pub struct CreatePricingRules<'info> {
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "pricing_rules".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump,
+        space = PricingRules::len(&params.holder_discounts),
+    )]
+    pub pricing_rules: Account<'info, PricingRules>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 1) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_pricing_rules_handler(
+    ctx: Context<CreatePricingRules>,
+    params: CreatePricingRulesParams,
+) -> Result<()> {
+    let pricing_rules = &mut ctx.accounts.pricing_rules;
+
+    if !PricingRules::is_valid(params.renewal_discount, &params.holder_discounts) {
+        return Err(error!(CustomError::InvalidPricingRules));
+    }
+
+    pricing_rules.bump = *ctx.bumps.get("pricing_rules").unwrap();
+    pricing_rules.version = PricingRules::VERSION;
+    pricing_rules.provider = ctx.accounts.provider.key();
+    pricing_rules.renewal_discount = params.renewal_discount;
+    pricing_rules.holder_discounts = params.holder_discounts;
+
+    // add `has pricing rules` flag (2048)
+    ctx.accounts.provider.flags |= 2048;
+
+    Ok(())
+}
@@ -1,6 +1,11 @@
-use anchor_lang::{prelude::*, system_program};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 
-use crate::state::{Global, Provider};
+use crate::{
+    state::{DataSchema, FeeVault, Global, Provider, RateLimit, SubProviderApproval, Tombstone},
+    utils::transfer_fee,
+    CustomError,
+};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct CreateProviderParams {
@@ -29,10 +34,36 @@ pub struct CreateProvider<'info> {
 
     #[account(
         mut,
-        constraint = treasury.key() == global.treasury.key()
+        seeds = [
+            "tombstone".as_bytes(),
+            params.name.as_bytes(),
+        ],
+        bump,
+    )]
+    /// CHECK: only exists if a provider with the same name has been closed
+    pub tombstone: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            "vault".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump,
+        space = FeeVault::len(),
     )]
-    /// CHECK: constraint to global treasury
-    pub treasury: UncheckedAccount<'info>,
+    pub provider_vault: Account<'info, FeeVault>,
+
+    #[account(
+        mut,
+        seeds = [
+            "vault".as_bytes(),
+            global.key().as_ref(),
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Box<Account<'info, FeeVault>>,
 
     #[account(
         seeds = ["global".as_bytes()],
@@ -40,6 +71,29 @@ pub struct CreateProvider<'info> {
     )]
     pub global: Box<Account<'info, Global>>,
 
+    /// Parent provider, only if the name is a sub-namespace (`parent/child`).
+    #[account(mut)]
+    pub parent: Option<Box<Account<'info, Provider>>>,
+
+    /// Parent's approval for the owner to create the sub-namespace, required with the parent.
+    #[account(mut)]
+    pub approval: Option<Box<Account<'info, SubProviderApproval>>>,
+
+    /// Payer of the approval, receives its rent back, required with the approval.
+    #[account(mut)]
+    /// CHECK: checked against the payer stored in the approval
+    pub approval_payer: Option<UncheckedAccount<'info>>,
+
+    /// Owner's token account in the global payment mint, only if the creation fee is paid in SPL token.
+    #[account(mut)]
+    pub owner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Associated token account of the protocol vault, only if the creation fee is paid in SPL token.
+    #[account(mut)]
+    pub vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -49,26 +103,131 @@ pub fn create_provider_handler(
 ) -> Result<()> {
     let owner = &mut ctx.accounts.owner;
     let provider = &mut ctx.accounts.provider;
+    let tombstone = &mut ctx.accounts.tombstone;
 
-    // pay provider creation fee
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: owner.to_account_info(),
-            to: ctx.accounts.treasury.to_account_info(),
-        },
-    );
+    // consume the tombstone left by a closed provider with the same name
+    if tombstone.owner.key() == ctx.program_id.key() && !tombstone.data_is_empty() {
+        let tombstone_data =
+            Tombstone::try_deserialize(&mut tombstone.try_borrow_data()?.as_ref())?;
+
+        if tombstone_data.authority.key() != owner.key()
+            && Clock::get()?.unix_timestamp < tombstone_data.closed_at + tombstone_data.cooldown
+        {
+            return Err(error!(CustomError::ProviderCoolingDown));
+        }
+
+        let source_account_info = tombstone.to_account_info();
+        let dest_account_info = owner.to_account_info();
+
+        let dest_starting_lamports = dest_account_info.lamports();
+        **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+            .checked_add(source_account_info.lamports())
+            .unwrap();
+        **source_account_info.lamports.borrow_mut() = 0;
+
+        let mut source_data = source_account_info.data.borrow_mut();
+        source_data.fill(0);
+    }
 
-    system_program::transfer(cpi_ctx, ctx.accounts.global.provider_creation_fee)?;
+    // a sub-namespace requires the approval of its parent, which is consumed
+    provider.parent = if params.name.contains('/') {
+        let (parent, approval, approval_payer) = match (
+            ctx.accounts.parent.as_mut(),
+            ctx.accounts.approval.as_ref(),
+            ctx.accounts.approval_payer.as_ref(),
+        ) {
+            (Some(parent), Some(approval), Some(approval_payer)) => {
+                (parent, approval, approval_payer)
+            }
+            _ => return Err(error!(CustomError::SubProviderUnauthorized)),
+        };
+
+        if !parent.is_parent_of(&params.name)
+            || approval.parent.key() != parent.key()
+            || approval.owner.key() != owner.key()
+            || approval.name != params.name
+            || approval.payer.key() != approval_payer.key()
+        {
+            return Err(error!(CustomError::SubProviderUnauthorized));
+        }
+
+        let source_account_info = approval.to_account_info();
+        let dest_account_info = approval_payer.to_account_info();
+
+        let dest_starting_lamports = dest_account_info.lamports();
+        **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+            .checked_add(source_account_info.lamports())
+            .unwrap();
+        **source_account_info.lamports.borrow_mut() = 0;
+
+        let mut source_data = source_account_info.data.borrow_mut();
+        source_data.fill(0);
+
+        parent.approval_count = parent.approval_count.saturating_sub(1);
+        parent.child_count += 1;
+
+        parent.key()
+    } else {
+        Pubkey::default()
+    };
+
+    let token_program = ctx
+        .accounts
+        .token_program
+        .as_ref()
+        .map(|token_program| token_program.to_account_info());
+
+    // pay provider creation fee
+    transfer_fee(
+        &ctx.accounts.global.payment_mint,
+        ctx.accounts.global.provider_creation_fee,
+        &owner.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts
+            .owner_token_account
+            .as_deref()
+            .zip(ctx.accounts.vault_token_account.as_deref()),
+        &ctx.accounts.system_program.to_account_info(),
+        token_program.as_ref(),
+    )?;
 
     provider.bump = *ctx.bumps.get("provider").unwrap();
+    provider.version = Provider::VERSION;
     provider.authority = owner.key();
     provider.flags = 1;
     provider.treasury = params.provider_treasury.key();
     provider.registration_fee = params.registration_fee;
     provider.validator_count = 0;
     provider.selling_price = 0;
+    provider.payment_mint = Pubkey::default();
+    provider.verified_bindie = Pubkey::default();
+    provider.pending_authority = Pubkey::default();
+    provider.min_custom_fee = 0;
+    provider.max_custom_fee = u64::MAX;
+    provider.refund_window = 0;
+    provider.refund_share = 0;
+    provider.subscription_period = 0;
+    provider.data_schema = DataSchema::default();
+    provider.rate_limit = RateLimit::default();
+    provider.guardian = Pubkey::default();
+    provider.unpause_at = 0;
+    provider.issued_count = 0;
+    provider.live_count = 0;
+    provider.voided_count = 0;
+    provider.collected_fees = 0;
+    provider.delegate_count = 0;
+    provider.license_count = 0;
+    provider.coupon_count = 0;
+    provider.approval_count = 0;
+    provider.child_count = 0;
+    provider.authority_epoch = 0;
     provider.name = params.name;
 
+    let provider_vault = &mut ctx.accounts.provider_vault;
+
+    provider_vault.bump = *ctx.bumps.get("provider_vault").unwrap();
+    provider_vault.version = FeeVault::VERSION;
+    provider_vault.owner = provider.key();
+
     Ok(())
 }
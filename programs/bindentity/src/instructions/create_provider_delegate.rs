@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Provider, ProviderDelegate};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateProviderDelegateParams {
+    delegate: Pubkey,
+    roles: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateProviderDelegateParams)]
+pub struct CreateProviderDelegate<'info> {
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "provider_delegate".as_bytes(),
+            provider.key().as_ref(),
+            params.delegate.key().as_ref(),
+        ],
+        bump,
+        space = ProviderDelegate::len(),
+    )]
+    pub provider_delegate: Account<'info, ProviderDelegate>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_provider_delegate_handler(
+    ctx: Context<CreateProviderDelegate>,
+    params: CreateProviderDelegateParams,
+) -> Result<()> {
+    let provider_delegate = &mut ctx.accounts.provider_delegate;
+
+    provider_delegate.bump = *ctx.bumps.get("provider_delegate").unwrap();
+    provider_delegate.version = ProviderDelegate::VERSION;
+    provider_delegate.roles = params.roles;
+    provider_delegate.provider = ctx.accounts.provider.key();
+    provider_delegate.delegate = params.delegate.key();
+    provider_delegate.authority_epoch = ctx.accounts.provider.authority_epoch;
+
+    ctx.accounts.provider.delegate_count += 1;
+
+    Ok(())
+}
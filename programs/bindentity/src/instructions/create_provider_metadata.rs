@@ -1,10 +1,17 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Provider, ProviderMetadata};
+use crate::{
+    state::{Provider, ProviderDelegate, ProviderMetadata},
+    CustomError,
+};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct CreateProviderMetadataParams {
     uri: String,
+    content_hash: Option<[u8; 32]>,
+    display_name: String,
+    registration_url: String,
+    tags: Vec<String>,
 }
 
 #[derive(Accounts)]
@@ -18,13 +25,27 @@ pub struct CreateProviderMetadata<'info> {
             provider.key().as_ref(),
         ],
         bump,
-        space = ProviderMetadata::len(&params.uri),
+        space = ProviderMetadata::len(
+            &params.uri,
+            &params.display_name,
+            &params.registration_url,
+            &params.tags,
+        ),
     )]
     pub provider_metadata: Account<'info, ProviderMetadata>,
 
-    #[account(mut, has_one = authority)]
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 4) @ CustomError::RoleUnauthorized,
+    )]
     pub provider: Account<'info, Provider>,
 
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -39,7 +60,12 @@ pub fn create_provider_metadata_handler(
     let provider = &mut ctx.accounts.provider;
 
     metadata.bump = *ctx.bumps.get("provider_metadata").unwrap();
+    metadata.version = ProviderMetadata::VERSION;
     metadata.uri = params.uri;
+    metadata.content_hash = params.content_hash;
+    metadata.display_name = params.display_name;
+    metadata.registration_url = params.registration_url;
+    metadata.tags = params.tags;
     provider.flags |= 32;
 
     Ok(())
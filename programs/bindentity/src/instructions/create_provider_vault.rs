@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{FeeVault, Provider};
+
+#[derive(Accounts)]
+pub struct CreateProviderVault<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            "vault".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump,
+        space = FeeVault::len(),
+    )]
+    pub provider_vault: Account<'info, FeeVault>,
+
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the vault of a provider created before vaults existed, anyone can pay for it.
+pub fn create_provider_vault_handler(ctx: Context<CreateProviderVault>) -> Result<()> {
+    let provider_vault = &mut ctx.accounts.provider_vault;
+
+    provider_vault.bump = *ctx.bumps.get("provider_vault").unwrap();
+    provider_vault.version = FeeVault::VERSION;
+    provider_vault.owner = ctx.accounts.provider.key();
+
+    Ok(())
+}
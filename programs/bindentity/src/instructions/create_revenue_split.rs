@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Provider, ProviderDelegate, RevenueSplit, SplitRecipient},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateRevenueSplitParams {
+    recipients: Vec<SplitRecipient>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateRevenueSplitParams)]
+pub struct CreateRevenueSplit<'info> {
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "revenue_split".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump,
+        space = RevenueSplit::len(&params.recipients),
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 1) @ CustomError::RoleUnauthorized,
+        constraint = provider.refund_window == 0 @ CustomError::InvalidRefundPolicy,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_revenue_split_handler(
+    ctx: Context<CreateRevenueSplit>,
+    params: CreateRevenueSplitParams,
+) -> Result<()> {
+    let revenue_split = &mut ctx.accounts.revenue_split;
+    let provider = &mut ctx.accounts.provider;
+
+    if !RevenueSplit::is_valid(&params.recipients) {
+        return Err(error!(CustomError::InvalidRevenueSplit));
+    }
+
+    revenue_split.bump = *ctx.bumps.get("revenue_split").unwrap();
+    revenue_split.version = RevenueSplit::VERSION;
+    revenue_split.provider = provider.key();
+    revenue_split.recipients = params.recipients;
+    provider.flags |= 64;
+
+    Ok(())
+}
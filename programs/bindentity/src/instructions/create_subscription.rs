@@ -0,0 +1,67 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    state::{Bindie, Provider, Subscription},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateSubscriptionParams {
+    deposit: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateSubscriptionParams)]
+pub struct CreateSubscription<'info> {
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            "subscription".as_bytes(),
+            bindie.key().as_ref(),
+        ],
+        bump,
+        space = Subscription::len(),
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    #[account(
+        has_one = owner,
+        has_one = provider,
+    )]
+    pub bindie: Account<'info, Bindie>,
+
+    #[account(
+        constraint = provider.subscription_period > 0 @ CustomError::InvalidSubscription,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_subscription_handler(
+    ctx: Context<CreateSubscription>,
+    params: CreateSubscriptionParams,
+) -> Result<()> {
+    let subscription = &mut ctx.accounts.subscription;
+
+    subscription.bump = *ctx.bumps.get("subscription").unwrap();
+    subscription.version = Subscription::VERSION;
+    subscription.bindie = ctx.accounts.bindie.key();
+    subscription.owner = ctx.accounts.owner.key();
+    subscription.fee = ctx.accounts.provider.registration_fee;
+    subscription.period = ctx.accounts.provider.subscription_period;
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.owner.to_account_info(),
+            to: subscription.to_account_info(),
+        },
+    );
+
+    system_program::transfer(cpi_ctx, params.deposit)
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Provider, Validator};
+use crate::{
+    state::{Provider, ProviderDelegate, RateLimit, Validator},
+    CustomError,
+};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct CreateValidatorParams {
@@ -29,11 +32,18 @@ pub struct CreateValidator<'info> {
 
     #[account(
         mut,
-        has_one = authority,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 2) @ CustomError::RoleUnauthorized,
         constraint = provider.flags & 8 != 8,
+        constraint = provider.flags & 512 != 512 @ CustomError::ProviderPaused,
     )]
     pub provider: Account<'info, Provider>,
 
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -45,9 +55,14 @@ pub fn create_validator_handler(
     let validator = &mut ctx.accounts.validator;
 
     validator.bump = *ctx.bumps.get("validator").unwrap();
+    validator.version = Validator::VERSION;
     validator.flags = if params.enabled { 1 } else { 0 };
     validator.signer = params.signer.key();
     validator.provider = provider.key();
+    validator.issued_count = 0;
+    validator.voided_count = 0;
+    validator.rate_limit = RateLimit::default();
+    validator.license = Pubkey::default();
     provider.validator_count += 1;
     provider.flags |= 16;
 
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{FeeVault, Global};
+
+#[derive(Accounts)]
+pub struct CreateVault<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            "vault".as_bytes(),
+            global.key().as_ref(),
+        ],
+        bump,
+        space = FeeVault::len(),
+    )]
+    pub vault: Account<'info, FeeVault>,
+
+    #[account(
+        seeds = ["global".as_bytes()],
+        bump = global.bump
+    )]
+    pub global: Account<'info, Global>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the protocol vault of a global initialized before vaults existed, anyone can pay for it.
+pub fn create_vault_handler(ctx: Context<CreateVault>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.bump = *ctx.bumps.get("vault").unwrap();
+    vault.version = FeeVault::VERSION;
+    vault.owner = ctx.accounts.global.key();
+
+    Ok(())
+}
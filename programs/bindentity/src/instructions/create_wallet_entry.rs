@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{AccessList, Provider, ProviderDelegate, WalletEntry},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateWalletEntryParams {
+    wallet: Pubkey,
+    allowed: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateWalletEntryParams)]
+pub struct CreateWalletEntry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "wallet_entry".as_bytes(),
+            provider.key().as_ref(),
+            params.wallet.key().as_ref(),
+        ],
+        bump,
+        space = WalletEntry::len(),
+    )]
+    pub wallet_entry: Account<'info, WalletEntry>,
+
+    #[account(
+        mut,
+        seeds = [
+            "access_list".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = access_list.bump,
+    )]
+    pub access_list: Account<'info, AccessList>,
+
+    #[account(
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 4) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_wallet_entry_handler(
+    ctx: Context<CreateWalletEntry>,
+    params: CreateWalletEntryParams,
+) -> Result<()> {
+    let access_list = &mut ctx.accounts.access_list;
+    let wallet_entry = &mut ctx.accounts.wallet_entry;
+
+    wallet_entry.bump = *ctx.bumps.get("wallet_entry").unwrap();
+    wallet_entry.version = WalletEntry::VERSION;
+    wallet_entry.provider = ctx.accounts.provider.key();
+    wallet_entry.wallet = params.wallet.key();
+    wallet_entry.allowed = params.allowed;
+
+    if params.allowed {
+        access_list.allowed_count += 1;
+    } else {
+        access_list.denied_count += 1;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+    },
+};
+
+use crate::{
+    state::{Multisig, MultisigProposal},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigProposal<'info> {
+    #[account(
+        mut,
+        has_one = multisig,
+        constraint = !proposal.executed @ CustomError::ProposalExecuted,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            "multisig_signer".as_bytes(),
+            multisig.key().as_ref(),
+        ],
+        bump = multisig.signer_bump,
+    )]
+    /// CHECK: system owned PDA which signs on behalf of the multisig
+    pub multisig_signer: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = multisig.members.contains(&member.key()) @ CustomError::NotAMultisigMember,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// Invokes the proposed Bindentity instruction with the multisig signer PDA as signer.
+/// The accounts of the proposed instruction (and this program) are passed as remaining accounts.
+pub fn execute_multisig_proposal_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteMultisigProposal<'info>>,
+) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let multisig_signer = &ctx.accounts.multisig_signer;
+
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approval| multisig.members.contains(approval))
+        .count();
+
+    if approvals < multisig.threshold as usize {
+        return Err(error!(CustomError::NotEnoughApprovals));
+    }
+
+    proposal.executed = true;
+
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: proposal
+            .accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.pubkey,
+                is_signer: account.is_signer || account.pubkey == multisig_signer.key(),
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: proposal.data.clone(),
+    };
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(multisig_signer.to_account_info());
+
+    let multisig_key = multisig.key();
+    let signer_seeds: &[&[u8]] = &[
+        "multisig_signer".as_bytes(),
+        multisig_key.as_ref(),
+        &[multisig.signer_bump],
+    ];
+
+    invoke_signed(&instruction, &account_infos, &[signer_seeds])?;
+
+    Ok(())
+}
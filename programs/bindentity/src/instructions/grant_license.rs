@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{License, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct GrantLicenseParams {
+    licensee: Pubkey,
+    treasury: Pubkey,
+    registration_fee: u64,
+    parent_share: u16,
+    expires_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: GrantLicenseParams)]
+pub struct GrantLicense<'info> {
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "license".as_bytes(),
+            provider.key().as_ref(),
+            params.licensee.key().as_ref(),
+        ],
+        bump,
+        space = License::len(),
+    )]
+    pub license: Account<'info, License>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 2) @ CustomError::RoleUnauthorized,
+        constraint = provider.flags & 8 != 8,
+        constraint = provider.refund_window == 0 @ CustomError::InvalidRefundPolicy,
+        constraint = provider.subscription_period == 0 @ CustomError::InvalidSubscription,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn grant_license_handler(ctx: Context<GrantLicense>, params: GrantLicenseParams) -> Result<()> {
+    let license = &mut ctx.accounts.license;
+
+    if params.parent_share > 10000 || params.expires_at <= Clock::get()?.unix_timestamp {
+        return Err(error!(CustomError::InvalidLicense));
+    }
+
+    license.bump = *ctx.bumps.get("license").unwrap();
+    license.version = License::VERSION;
+    license.provider = ctx.accounts.provider.key();
+    license.licensee = params.licensee.key();
+    license.treasury = params.treasury.key();
+    license.registration_fee = params.registration_fee;
+    license.parent_share = params.parent_share;
+    license.expires_at = params.expires_at;
+    license.validator_count = 0;
+    license.issued_count = 0;
+
+    ctx.accounts.provider.license_count += 1;
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{program::Bindentity, state::Global};
+use crate::{
+    program::Bindentity,
+    state::{FeeVault, Global},
+};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct InitializeParams {
@@ -23,6 +26,18 @@ pub struct Initialize<'info> {
     )]
     pub global: Account<'info, Global>,
 
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "vault".as_bytes(),
+            global.key().as_ref(),
+        ],
+        bump,
+        space = FeeVault::len(),
+    )]
+    pub vault: Account<'info, FeeVault>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -43,10 +58,17 @@ pub fn initialize_handler(ctx: Context<Initialize>, params: InitializeParams) ->
     let global = &mut ctx.accounts.global;
 
     global.bump = *ctx.bumps.get("global").unwrap();
+    global.version = Global::VERSION;
     global.authority = ctx.accounts.authority.key();
     global.treasury = params.treasury.key();
     global.provider_creation_fee = params.provider_creation_fee;
     global.service_fee = params.service_fee;
 
+    let vault = &mut ctx.accounts.vault;
+
+    vault.bump = *ctx.bumps.get("vault").unwrap();
+    vault.version = FeeVault::VERSION;
+    vault.owner = global.key();
+
     Ok(())
 }
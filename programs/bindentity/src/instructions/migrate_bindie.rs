@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::Bindie,
+    utils::{legacy_len_with_string, migrate_legacy_account},
+};
+
+#[derive(Accounts)]
+pub struct MigrateBindie<'info> {
+    /// CHECK: legacy account, validated by its owner, discriminator and length
+    #[account(mut)]
+    pub bindie: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Appends the version and the fields added since.
+pub fn migrate_bindie_handler(ctx: Context<MigrateBindie>) -> Result<()> {
+    // legacy layout, followed by the data
+    let data_offset = 8 + 1 + 32 + 32 + 8 + 1;
+    let legacy_len = legacy_len_with_string(&ctx.accounts.bindie, data_offset)?;
+
+    migrate_legacy_account::<Bindie>(
+        &ctx.accounts.bindie.to_account_info(),
+        legacy_len,
+        legacy_len - (data_offset + 4) + Bindie::len(String::new()),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |bindie| {
+            bindie.version = Bindie::VERSION;
+
+            Ok(())
+        },
+    )
+}
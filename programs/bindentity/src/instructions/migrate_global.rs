@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Global, utils::migrate_legacy_account, CustomError};
+
+#[derive(Accounts)]
+pub struct MigrateGlobal<'info> {
+    /// CHECK: legacy account, validated by its owner, discriminator and length
+    #[account(mut)]
+    pub global: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the version, the fields added since are carved out of the legacy reserved space
+/// so the account keeps its size.
+pub fn migrate_global_handler(ctx: Context<MigrateGlobal>) -> Result<()> {
+    // legacy layout, the same size as the current one
+    let legacy_len = 8 + 1 + 32 + 32 + 8 + 8 + 128;
+
+    migrate_legacy_account::<Global>(
+        &ctx.accounts.global.to_account_info(),
+        legacy_len,
+        Global::len(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |global| {
+            // the length does not tell a legacy global apart
+            if global.version != 0 {
+                return Err(error!(CustomError::InvalidMigration));
+            }
+
+            global.version = Global::VERSION;
+
+            Ok(())
+        },
+    )
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Link, utils::migrate_legacy_account};
+
+#[derive(Accounts)]
+pub struct MigrateLink<'info> {
+    /// CHECK: legacy account, validated by its owner, discriminator and length
+    #[account(mut)]
+    pub link: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Appends the version and the reserved space.
+pub fn migrate_link_handler(ctx: Context<MigrateLink>) -> Result<()> {
+    // legacy layout
+    let legacy_len = 8 + 1 + 32;
+
+    migrate_legacy_account::<Link>(
+        &ctx.accounts.link.to_account_info(),
+        legacy_len,
+        Link::len(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |link| {
+            link.version = Link::VERSION;
+
+            Ok(())
+        },
+    )
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::Provider,
+    utils::{legacy_len_with_string, migrate_legacy_account},
+};
+
+#[derive(Accounts)]
+pub struct MigrateProvider<'info> {
+    /// CHECK: legacy account, validated by its owner, discriminator and length
+    #[account(mut)]
+    pub provider: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Appends the version and the fields added since, the counters start from the migration.
+/// The bindies issued before are not counted in `live_count`, so the provider is flagged as migrated (4096)
+/// which prevents it from being closed.
+pub fn migrate_provider_handler(ctx: Context<MigrateProvider>) -> Result<()> {
+    // legacy layout, followed by the name
+    let name_offset = 8 + 1 + 2 + 32 + 32 + 8 + 4 + 8 + 32;
+    let legacy_len = legacy_len_with_string(&ctx.accounts.provider, name_offset)?;
+
+    migrate_legacy_account::<Provider>(
+        &ctx.accounts.provider.to_account_info(),
+        legacy_len,
+        legacy_len - (name_offset + 4) + Provider::len(&String::new()),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |provider| {
+            provider.version = Provider::VERSION;
+            provider.max_custom_fee = u64::MAX;
+            provider.flags |= 4096;
+
+            Ok(())
+        },
+    )
+}
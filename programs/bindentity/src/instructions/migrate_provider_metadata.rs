@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::ProviderMetadata,
+    utils::{legacy_len_with_string, migrate_legacy_account},
+};
+
+#[derive(Accounts)]
+pub struct MigrateProviderMetadata<'info> {
+    /// CHECK: legacy account, validated by its owner, discriminator and length
+    #[account(mut)]
+    pub provider_metadata: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Appends the version and the on-chain fields, which stay empty until the metadata is updated.
+pub fn migrate_provider_metadata_handler(ctx: Context<MigrateProviderMetadata>) -> Result<()> {
+    // legacy layout, followed by the uri
+    let uri_offset = 8 + 1;
+    let legacy_len = legacy_len_with_string(&ctx.accounts.provider_metadata, uri_offset)?;
+
+    migrate_legacy_account::<ProviderMetadata>(
+        &ctx.accounts.provider_metadata.to_account_info(),
+        legacy_len,
+        legacy_len - (uri_offset + 4) + ProviderMetadata::len("", "", "", &[]),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |metadata| {
+            metadata.version = ProviderMetadata::VERSION;
+
+            Ok(())
+        },
+    )
+}
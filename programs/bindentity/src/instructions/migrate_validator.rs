@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Validator, utils::migrate_legacy_account};
+
+#[derive(Accounts)]
+pub struct MigrateValidator<'info> {
+    /// CHECK: legacy account, validated by its owner, discriminator and length
+    #[account(mut)]
+    pub validator: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Appends the version and the fields added since.
+pub fn migrate_validator_handler(ctx: Context<MigrateValidator>) -> Result<()> {
+    // legacy layout
+    let legacy_len = 8 + 1 + 1 + 32 + 32;
+
+    migrate_legacy_account::<Validator>(
+        &ctx.accounts.validator.to_account_info(),
+        legacy_len,
+        Validator::len(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |validator| {
+            validator.version = Validator::VERSION;
+
+            Ok(())
+        },
+    )
+}
@@ -1,23 +1,153 @@
+mod accept_config_authority;
+mod accept_offer;
+mod accept_provider_authority;
+mod approve_multisig_proposal;
+mod approve_sub_provider;
 mod buy_provider;
+mod cancel_config_authority;
+mod cancel_offer;
+mod cancel_provider_authority;
+mod cancel_subscription;
+mod close_license_validators;
+mod close_provider;
+mod collect_subscription;
+mod create_access_list;
 mod create_bindie;
+mod create_coupon;
+mod create_license_validator;
+mod create_multisig;
+mod create_multisig_proposal;
+mod create_prerequisites;
+mod create_pricing_rules;
 mod create_provider;
+mod create_provider_delegate;
 mod create_provider_metadata;
+mod create_provider_vault;
+mod create_revenue_split;
+mod create_subscription;
 mod create_validator;
+mod create_vault;
+mod create_wallet_entry;
+mod execute_multisig_proposal;
+mod grant_license;
 mod initialize;
+mod migrate_bindie;
+mod migrate_global;
+mod migrate_link;
+mod migrate_provider;
+mod migrate_provider_metadata;
+mod migrate_validator;
+mod pause_provider;
+mod place_bid;
+mod place_offer;
+mod propose_config_authority;
+mod propose_provider_authority;
+mod release_escrow;
+mod remove_access_list;
+mod remove_prerequisites;
+mod remove_pricing_rules;
+mod remove_provider_metadata;
+mod remove_revenue_split;
+mod remove_wallet_entry;
+mod request_unpause_provider;
+mod revoke_license;
+mod revoke_sub_provider;
+mod settle_auction;
+mod start_auction;
+mod unpause_provider;
+mod unverify_provider;
+mod update_access_list;
 mod update_config;
+mod update_coupon;
+mod update_license;
+mod update_license_validator;
+mod update_multisig;
+mod update_prerequisites;
+mod update_pricing_rules;
 mod update_provider;
+mod update_provider_delegate;
+mod update_provider_metadata;
+mod update_revenue_split;
 mod update_validator;
 mod verify_provider;
 mod void_bindie;
+mod void_lapsed_bindie;
+mod withdraw_protocol_fees;
+mod withdraw_provider_fees;
 
+pub use accept_config_authority::*;
+pub use accept_offer::*;
+pub use accept_provider_authority::*;
+pub use approve_multisig_proposal::*;
+pub use approve_sub_provider::*;
 pub use buy_provider::*;
+pub use cancel_config_authority::*;
+pub use cancel_offer::*;
+pub use cancel_provider_authority::*;
+pub use cancel_subscription::*;
+pub use close_license_validators::*;
+pub use close_provider::*;
+pub use collect_subscription::*;
+pub use create_access_list::*;
 pub use create_bindie::*;
+pub use create_coupon::*;
+pub use create_license_validator::*;
+pub use create_multisig::*;
+pub use create_multisig_proposal::*;
+pub use create_prerequisites::*;
+pub use create_pricing_rules::*;
 pub use create_provider::*;
+pub use create_provider_delegate::*;
 pub use create_provider_metadata::*;
+pub use create_provider_vault::*;
+pub use create_revenue_split::*;
+pub use create_subscription::*;
 pub use create_validator::*;
+pub use create_vault::*;
+pub use create_wallet_entry::*;
+pub use execute_multisig_proposal::*;
+pub use grant_license::*;
 pub use initialize::*;
+pub use migrate_bindie::*;
+pub use migrate_global::*;
+pub use migrate_link::*;
+pub use migrate_provider::*;
+pub use migrate_provider_metadata::*;
+pub use migrate_validator::*;
+pub use pause_provider::*;
+pub use place_bid::*;
+pub use place_offer::*;
+pub use propose_config_authority::*;
+pub use propose_provider_authority::*;
+pub use release_escrow::*;
+pub use remove_access_list::*;
+pub use remove_prerequisites::*;
+pub use remove_pricing_rules::*;
+pub use remove_provider_metadata::*;
+pub use remove_revenue_split::*;
+pub use remove_wallet_entry::*;
+pub use request_unpause_provider::*;
+pub use revoke_license::*;
+pub use revoke_sub_provider::*;
+pub use settle_auction::*;
+pub use start_auction::*;
+pub use unpause_provider::*;
+pub use unverify_provider::*;
+pub use update_access_list::*;
 pub use update_config::*;
+pub use update_coupon::*;
+pub use update_license::*;
+pub use update_license_validator::*;
+pub use update_multisig::*;
+pub use update_prerequisites::*;
+pub use update_pricing_rules::*;
 pub use update_provider::*;
+pub use update_provider_delegate::*;
+pub use update_provider_metadata::*;
+pub use update_revenue_split::*;
 pub use update_validator::*;
 pub use verify_provider::*;
 pub use void_bindie::*;
+pub use void_lapsed_bindie::*;
+pub use withdraw_protocol_fees::*;
+pub use withdraw_provider_fees::*;
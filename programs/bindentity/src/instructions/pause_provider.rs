@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Provider, CustomError};
+
+#[derive(Accounts)]
+pub struct PauseProvider<'info> {
+    #[account(
+        mut,
+        constraint = provider.authority.key() == signer.key()
+            || provider.guardian.key() == signer.key() @ CustomError::PauseUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    pub signer: Signer<'info>,
+}
+
+/// Takes effect immediately. Only the authority's pause cancels a pending unpause request,
+/// so that a compromised guardian cannot keep the provider paused.
+pub fn pause_provider_handler(ctx: Context<PauseProvider>) -> Result<()> {
+    let provider = &mut ctx.accounts.provider;
+
+    provider.flags |= 512;
+
+    if provider.authority.key() == ctx.accounts.signer.key() {
+        provider.unpause_at = 0;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{state::Auction, CustomError};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct PlaceBidParams {
+    amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: PlaceBidParams)]
+pub struct PlaceBid<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "auction".as_bytes(),
+            auction.provider.key().as_ref(),
+        ],
+        bump = auction.bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = previous_bidder.key() == auction.highest_bidder.key() @ CustomError::InvalidAuction,
+    )]
+    /// CHECK: constraint to the highest bidder, required if there is a bid to refund
+    pub previous_bidder: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Escrows the bid in the auction account and refunds the outbid bidder.
+/// A bid placed within `extension` seconds of the end extends the auction, so it cannot be sniped.
+pub fn place_bid_handler(ctx: Context<PlaceBid>, params: PlaceBidParams) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let now = Clock::get()?.unix_timestamp;
+
+    if now >= auction.ends_at {
+        return Err(error!(CustomError::AuctionEnded));
+    }
+
+    if params.amount < auction.min_bid() {
+        return Err(error!(CustomError::BidTooLow));
+    }
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.bidder.to_account_info(),
+            to: auction.to_account_info(),
+        },
+    );
+
+    system_program::transfer(cpi_ctx, params.amount)?;
+
+    if auction.highest_bidder.key() != Pubkey::default() {
+        let previous_bidder = match ctx.accounts.previous_bidder.as_ref() {
+            Some(previous_bidder) => previous_bidder,
+            None => return Err(error!(CustomError::InvalidAuction)),
+        };
+
+        let source_account_info = auction.to_account_info();
+        let dest_account_info = previous_bidder.to_account_info();
+
+        **source_account_info.lamports.borrow_mut() -= auction.highest_bid;
+        **dest_account_info.lamports.borrow_mut() = dest_account_info
+            .lamports()
+            .checked_add(auction.highest_bid)
+            .unwrap();
+    }
+
+    auction.highest_bid = params.amount;
+    auction.highest_bidder = ctx.accounts.bidder.key();
+
+    if auction.ends_at - now < auction.extension {
+        auction.ends_at = now + auction.extension;
+    }
+
+    Ok(())
+}
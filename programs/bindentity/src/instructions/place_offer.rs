@@ -0,0 +1,69 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    state::{Offer, Provider},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct PlaceOfferParams {
+    amount: u64,
+    provider_treasury: Pubkey,
+    registration_fee: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: PlaceOfferParams)]
+pub struct PlaceOffer<'info> {
+    #[account(
+        init,
+        payer = offerer,
+        seeds = [
+            "offer".as_bytes(),
+            provider.key().as_ref(),
+            offerer.key().as_ref(),
+        ],
+        bump,
+        space = Offer::len(),
+    )]
+    pub offer: Account<'info, Offer>,
+
+    #[account(
+        constraint = provider.authority.key() != offerer.key() @ CustomError::InvalidOffer,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut)]
+    pub offerer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Escrows the offered amount in the offer account, one offer per offerer and provider.
+pub fn place_offer_handler(ctx: Context<PlaceOffer>, params: PlaceOfferParams) -> Result<()> {
+    let offer = &mut ctx.accounts.offer;
+
+    if params.amount == 0 {
+        return Err(error!(CustomError::InvalidOffer));
+    }
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.offerer.to_account_info(),
+            to: offer.to_account_info(),
+        },
+    );
+
+    system_program::transfer(cpi_ctx, params.amount)?;
+
+    offer.bump = *ctx.bumps.get("offer").unwrap();
+    offer.version = Offer::VERSION;
+    offer.provider = ctx.accounts.provider.key();
+    offer.offerer = ctx.accounts.offerer.key();
+    offer.amount = params.amount;
+    offer.provider_treasury = params.provider_treasury;
+    offer.registration_fee = params.registration_fee;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Global;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ProposeConfigAuthorityParams {
+    pub pending_authority: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ProposeConfigAuthorityParams)]
+pub struct ProposeConfigAuthority<'info> {
+    #[account(
+        mut,
+        seeds = ["global".as_bytes()],
+        bump = global.bump
+    )]
+    pub global: Account<'info, Global>,
+
+    #[account(
+        constraint = authority.key() == global.authority.key()
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// First step of handing over the protocol, the proposed account has to accept before it takes effect.
+pub fn propose_config_authority_handler(
+    ctx: Context<ProposeConfigAuthority>,
+    params: ProposeConfigAuthorityParams,
+) -> Result<()> {
+    let global = &mut ctx.accounts.global;
+
+    global.pending_authority = params.pending_authority.key();
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Provider, CustomError};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ProposeProviderAuthorityParams {
+    pending_authority: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ProposeProviderAuthorityParams)]
+pub struct ProposeProviderAuthority<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = provider.flags & 8 != 8 @ CustomError::ProviderListed,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    pub authority: Signer<'info>,
+}
+
+/// First step of handing over a provider, the proposed account has to accept before it takes effect.
+pub fn propose_provider_authority_handler(
+    ctx: Context<ProposeProviderAuthority>,
+    params: ProposeProviderAuthorityParams,
+) -> Result<()> {
+    let provider = &mut ctx.accounts.provider;
+
+    provider.pending_authority = params.pending_authority.key();
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{FeeEscrow, FeeVault},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct ReleaseEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "escrow".as_bytes(),
+            escrow.bindie.as_ref(),
+        ],
+        bump = escrow.bump,
+        has_one = owner,
+    )]
+    pub escrow: Account<'info, FeeEscrow>,
+
+    #[account(
+        mut,
+        seeds = [
+            "vault".as_bytes(),
+            escrow.provider.as_ref(),
+        ],
+        bump = provider_vault.bump,
+    )]
+    pub provider_vault: Account<'info, FeeVault>,
+
+    #[account(mut)]
+    /// CHECK: constraint to the escrow's owner, receives the rent
+    pub owner: UncheckedAccount<'info>,
+}
+
+/// Releases the escrowed fee to the provider vault once the refund window has passed, can be called by anyone.
+pub fn release_escrow_handler(ctx: Context<ReleaseEscrow>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+
+    if Clock::get()?.unix_timestamp < escrow.release_at {
+        return Err(error!(CustomError::EscrowLocked));
+    }
+
+    FeeEscrow::settle(
+        escrow,
+        0,
+        &ctx.accounts.provider_vault.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+    )
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{AccessList, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct RemoveAccessList<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "access_list".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = access_list.bump,
+        constraint = access_list.allowed_count == 0 @ CustomError::InvalidAccessList,
+        constraint = access_list.denied_count == 0 @ CustomError::InvalidAccessList,
+    )]
+    pub access_list: Account<'info, AccessList>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 4) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Closes the access list once all of its wallet entries are removed, registration is open to any wallet again.
+pub fn remove_access_list_handler(ctx: Context<RemoveAccessList>) -> Result<()> {
+    let authority = &mut ctx.accounts.authority;
+    let provider = &mut ctx.accounts.provider;
+    let access_list = &mut ctx.accounts.access_list;
+
+    let source_account_info = access_list.to_account_info();
+    let dest_account_info = authority.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    // remove `has access list` flag (256)
+    provider.flags &= 65279;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Prerequisites, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct RemovePrerequisites<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "prerequisites".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = prerequisites.bump,
+    )]
+    pub prerequisites: Account<'info, Prerequisites>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 4) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Closes the prerequisites, anyone can register again without holding other bindies.
+pub fn remove_prerequisites_handler(ctx: Context<RemovePrerequisites>) -> Result<()> {
+    let authority = &mut ctx.accounts.authority;
+    let provider = &mut ctx.accounts.provider;
+    let prerequisites = &mut ctx.accounts.prerequisites;
+
+    let source_account_info = prerequisites.to_account_info();
+    let dest_account_info = authority.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    // remove `has prerequisites` flag (128)
+    provider.flags &= 65407;
+
+    Ok(())
+}
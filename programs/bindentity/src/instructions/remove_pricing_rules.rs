@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{PricingRules, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct RemovePricingRules<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "pricing_rules".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = pricing_rules.bump,
+    )]
+    pub pricing_rules: Account<'info, PricingRules>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 1) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Closes the pricing rules, registrations are charged the full fee again.
+pub fn remove_pricing_rules_handler(ctx: Context<RemovePricingRules>) -> Result<()> {
+    let authority = &mut ctx.accounts.authority;
+    let provider = &mut ctx.accounts.provider;
+    let pricing_rules = &mut ctx.accounts.pricing_rules;
+
+    let source_account_info = pricing_rules.to_account_info();
+    let dest_account_info = authority.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    // remove `has pricing rules` flag (2048)
+    provider.flags &= 63487;
+
+    Ok(())
+}
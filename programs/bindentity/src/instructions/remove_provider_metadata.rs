@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Provider, ProviderMetadata};
+use crate::{
+    state::{Provider, ProviderDelegate, ProviderMetadata},
+    CustomError,
+};
 
 #[derive(Accounts)]
 pub struct RemoveProviderMetadata<'info> {
@@ -14,9 +17,18 @@ pub struct RemoveProviderMetadata<'info> {
     )]
     pub provider_metadata: Account<'info, ProviderMetadata>,
 
-    #[account(mut, has_one = authority)]
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 4) @ CustomError::RoleUnauthorized,
+    )]
     pub provider: Account<'info, Provider>,
 
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Provider, ProviderDelegate, RevenueSplit},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct RemoveRevenueSplit<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "revenue_split".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = revenue_split.bump,
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 1) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Closes the revenue split, the registration fees go back to the provider vault.
+pub fn remove_revenue_split_handler(ctx: Context<RemoveRevenueSplit>) -> Result<()> {
+    let authority = &mut ctx.accounts.authority;
+    let provider = &mut ctx.accounts.provider;
+    let revenue_split = &mut ctx.accounts.revenue_split;
+
+    let source_account_info = revenue_split.to_account_info();
+    let dest_account_info = authority.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    // remove `has revenue split` flag (64)
+    provider.flags &= 65471;
+
+    Ok(())
+}
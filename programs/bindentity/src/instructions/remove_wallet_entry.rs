@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{AccessList, Provider, ProviderDelegate, WalletEntry},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct RemoveWalletEntry<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "wallet_entry".as_bytes(),
+            provider.key().as_ref(),
+            wallet_entry.wallet.key().as_ref(),
+        ],
+        bump = wallet_entry.bump,
+    )]
+    pub wallet_entry: Account<'info, WalletEntry>,
+
+    #[account(
+        mut,
+        seeds = [
+            "access_list".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = access_list.bump,
+    )]
+    pub access_list: Account<'info, AccessList>,
+
+    #[account(
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 4) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn remove_wallet_entry_handler(ctx: Context<RemoveWalletEntry>) -> Result<()> {
+    let access_list = &mut ctx.accounts.access_list;
+    let wallet_entry = &mut ctx.accounts.wallet_entry;
+
+    if wallet_entry.allowed {
+        access_list.allowed_count -= 1;
+    } else {
+        access_list.denied_count -= 1;
+    }
+
+    let source_account_info = wallet_entry.to_account_info();
+    let dest_account_info = ctx.accounts.authority.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Global, Provider},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct RequestUnpauseProvider<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = provider.flags & 512 == 512 @ CustomError::UnpauseTimelocked,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        seeds = ["global".as_bytes()],
+        bump = global.bump
+    )]
+    pub global: Account<'info, Global>,
+
+    pub authority: Signer<'info>,
+}
+
+/// First step of unpausing a provider, it can be unpaused once the global timelock has passed.
+pub fn request_unpause_provider_handler(ctx: Context<RequestUnpauseProvider>) -> Result<()> {
+    let provider = &mut ctx.accounts.provider;
+
+    provider.unpause_at = Clock::get()?.unix_timestamp + ctx.accounts.global.unpause_timelock;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{License, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct RevokeLicense<'info> {
+    #[account(
+        mut,
+        has_one = provider,
+    )]
+    pub license: Account<'info, License>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 2) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Expires the license right away, so that neither it nor its validators can issue or void bindies.
+/// The license is only closed once its validators are closed through `close_license_validators`,
+/// until then this can be called again to close it.
+pub fn revoke_license_handler(ctx: Context<RevokeLicense>) -> Result<()> {
+    let license = &mut ctx.accounts.license;
+    let now = Clock::get()?.unix_timestamp;
+
+    if license.is_active(now) {
+        license.expires_at = now;
+    }
+
+    if license.validator_count != 0 {
+        return Ok(());
+    }
+
+    let source_account_info = license.to_account_info();
+    let dest_account_info = ctx.accounts.authority.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    let provider = &mut ctx.accounts.provider;
+    provider.license_count = provider.license_count.saturating_sub(1);
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Provider, ProviderDelegate, SubProviderApproval},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct RevokeSubProvider<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "sub_provider_approval".as_bytes(),
+            parent.key().as_ref(),
+            approval.name.as_bytes(),
+        ],
+        bump = approval.bump,
+        has_one = payer,
+    )]
+    pub approval: Account<'info, SubProviderApproval>,
+
+    #[account(
+        mut,
+        constraint = parent.has_role(&authority.key(), delegate.as_deref(), 8) @ CustomError::RoleUnauthorized,
+    )]
+    pub parent: Account<'info, Provider>,
+
+    #[account(
+        constraint = delegate.provider == parent.key(),
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: constraint to the payer of the approval, receives the rent back
+    pub payer: UncheckedAccount<'info>,
+}
+
+/// Closes an approval which has not been consumed yet, the rent goes back to its payer.
+pub fn revoke_sub_provider_handler(ctx: Context<RevokeSubProvider>) -> Result<()> {
+    let source_account_info = ctx.accounts.approval.to_account_info();
+    let dest_account_info = ctx.accounts.payer.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    let parent = &mut ctx.accounts.parent;
+    parent.approval_count = parent.approval_count.saturating_sub(1);
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Auction, Provider},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "auction".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = auction.bump,
+        has_one = seller,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(mut)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut)]
+    /// CHECK: constraint to the seller of the auction
+    pub seller: UncheckedAccount<'info>,
+}
+
+/// Can be called by anyone once the auction has ended.
+/// The highest bidder takes over the provider the same way as `buy_provider`,
+/// without bids the provider stays listed for sale.
+pub fn settle_auction_handler(ctx: Context<SettleAuction>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let provider = &mut ctx.accounts.provider;
+
+    if Clock::get()?.unix_timestamp < auction.ends_at {
+        return Err(error!(CustomError::AuctionNotEnded));
+    }
+
+    if auction.highest_bidder.key() != Pubkey::default() {
+        provider.reset_ownership(&auction.highest_bidder, &auction.highest_bidder, 0);
+    } else {
+        // remove `in auction` flag (1024)
+        provider.flags &= 64511;
+    }
+
+    // the winning bid and the rent go to the seller
+    let source_account_info = auction.to_account_info();
+    let dest_account_info = ctx.accounts.seller.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    Ok(())
+}
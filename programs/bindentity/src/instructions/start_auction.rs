@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Auction, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct StartAuctionParams {
+    reserve_price: u64,
+    min_increment: u64,
+    duration: i64,
+    extension: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: StartAuctionParams)]
+pub struct StartAuction<'info> {
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            "auction".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump,
+        space = Auction::len(),
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 8) @ CustomError::RoleUnauthorized,
+        constraint = provider.flags & 8 == 8 @ CustomError::SellingNotAllowed,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The provider has to be listed for sale, and stays listed until the auction is settled.
+pub fn start_auction_handler(ctx: Context<StartAuction>, params: StartAuctionParams) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let provider = &mut ctx.accounts.provider;
+
+    if params.duration <= 0 || params.extension < 0 || params.min_increment == 0 {
+        return Err(error!(CustomError::InvalidAuction));
+    }
+
+    auction.bump = *ctx.bumps.get("auction").unwrap();
+    auction.version = Auction::VERSION;
+    auction.provider = provider.key();
+    auction.seller = provider.authority.key();
+    auction.reserve_price = params.reserve_price;
+    auction.min_increment = params.min_increment;
+    auction.extension = params.extension;
+    auction.ends_at = Clock::get()?.unix_timestamp + params.duration;
+    auction.highest_bid = 0;
+    auction.highest_bidder = Pubkey::default();
+    provider.flags |= 1024;
+
+    Ok(())
+}
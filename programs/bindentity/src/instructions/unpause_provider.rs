@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Provider, CustomError};
+
+#[derive(Accounts)]
+pub struct UnpauseProvider<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = provider.flags & 512 == 512 @ CustomError::UnpauseTimelocked,
+        constraint = provider.unpause_at != 0 @ CustomError::UnpauseTimelocked,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn unpause_provider_handler(ctx: Context<UnpauseProvider>) -> Result<()> {
+    let provider = &mut ctx.accounts.provider;
+
+    if Clock::get()?.unix_timestamp < provider.unpause_at {
+        return Err(error!(CustomError::UnpauseTimelocked));
+    }
+
+    // remove paused flag (512)
+    provider.flags &= 65023;
+    provider.unpause_at = 0;
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Bindie, Link, Provider, Validator},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UnverifyProviderParams {
+    /// Data of the backing `provider` bindie, required when proving that it is void.
+    pub data: Option<String>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UnverifyProviderParams)]
+pub struct UnverifyProvider<'info> {
+    #[account(
+        seeds = [
+            "provider".as_bytes(),
+            "provider".as_bytes(),
+        ],
+        bump = verifier_provider.bump
+    )]
+    pub verifier_provider: Account<'info, Provider>,
+
+    #[account(
+        constraint = validator.provider.key() == verifier_provider.key(),
+        constraint = validator.flags & 4 == 4 @ CustomError::UnverifyUnauthorized,
+    )]
+    pub validator: Option<Account<'info, Validator>>,
+
+    pub signer: Option<Signer<'info>>,
+
+    #[account(
+        constraint = verified_bindie.key() == target_provider.verified_bindie.key(),
+        constraint = verified_bindie.provider.key() == verifier_provider.key(),
+    )]
+    pub verified_bindie: Option<Account<'info, Bindie>>,
+
+    /// CHECK: derived from the data of the verified bindie, may already be closed
+    pub verified_link: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        constraint = target_provider.flags & 4 == 4,
+    )]
+    pub target_provider: Account<'info, Provider>,
+}
+
+/// A provider's verification can be revoked in 2 ways:
+/// 1. Anyone can show that the `provider` bindie which backs the verification is void or lapsed
+/// 2. A validator of the `provider` provider that is permitted to void identities
+pub fn unverify_provider_handler(
+    ctx: Context<UnverifyProvider>,
+    params: UnverifyProviderParams,
+) -> Result<()> {
+    let verifier_provider = &ctx.accounts.verifier_provider;
+    let target_provider = &mut ctx.accounts.target_provider;
+
+    if let (Some(validator), Some(signer)) = (&ctx.accounts.validator, &ctx.accounts.signer) {
+        if signer.key() != validator.signer.key() {
+            return Err(error!(CustomError::UnverifyUnauthorized));
+        }
+    } else {
+        let (bindie, link, data) = match (
+            &ctx.accounts.verified_bindie,
+            &ctx.accounts.verified_link,
+            params.data,
+        ) {
+            (Some(bindie), Some(link), Some(data)) => (bindie, link, data),
+            _ => return Err(error!(CustomError::UnverifyUnauthorized)),
+        };
+
+        // the data should be the one used to derive the verified bindie
+        let bindie_address = Pubkey::create_program_address(
+            &[
+                "bindie".as_bytes(),
+                bindie.timestamp.to_string().as_bytes(),
+                verifier_provider.key().as_ref(),
+                Bindie::crop(&data).as_bytes(),
+                &[bindie.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| error!(CustomError::InvalidDataHash))?;
+
+        if bindie_address != bindie.key() {
+            return Err(error!(CustomError::InvalidDataHash));
+        }
+
+        let (link_address, _) = Pubkey::find_program_address(
+            &[
+                "link".as_bytes(),
+                verifier_provider.key().as_ref(),
+                Bindie::crop(&data).as_bytes(),
+            ],
+            ctx.program_id,
+        );
+
+        if link_address != link.key() {
+            return Err(error!(CustomError::UnverifyUnauthorized));
+        }
+
+        // the bindie is still valid if its link exists and points to it, and its subscription has not lapsed
+        if link.owner.key() == ctx.program_id.key() && !link.data_is_empty() {
+            let link_data = link.try_borrow_data()?;
+            let link_account = Link::try_deserialize(&mut link_data.as_ref())?;
+
+            if link_account.bindie.key() == bindie.key()
+                && bindie.is_active(Clock::get()?.unix_timestamp)
+            {
+                return Err(error!(CustomError::VerificationStillValid));
+            }
+        }
+    }
+
+    // remove verified flag (4)
+    target_provider.flags &= 65531;
+    target_provider.verified_bindie = Pubkey::default();
+
+    Ok(())
+}
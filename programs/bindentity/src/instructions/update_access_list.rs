@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{AccessList, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateAccessListParams {
+    allowlist_root: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdateAccessListParams)]
+pub struct UpdateAccessList<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "access_list".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = access_list.bump,
+    )]
+    pub access_list: Account<'info, AccessList>,
+
+    #[account(
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 4) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Replaces the merkle root of the allowed wallets, zeroes to clear it.
+pub fn update_access_list_handler(
+    ctx: Context<UpdateAccessList>,
+    params: UpdateAccessListParams,
+) -> Result<()> {
+    ctx.accounts.access_list.allowlist_root = params.allowlist_root;
+
+    Ok(())
+}
@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
 
-use crate::state::Global;
+use crate::{state::Global, utils::is_valid_payment_mint, CustomError};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct UpdateConfigParams {
-    pub authority: Option<Pubkey>,
     pub treasury: Option<Pubkey>,
     pub service_fee: Option<u64>,
     pub provider_creation_fee: Option<u64>,
+    pub provider_cooldown: Option<i64>,
+    pub payment_mint: Option<Pubkey>,
+    pub max_integrator_share: Option<u16>,
+    pub unpause_timelock: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -25,6 +29,9 @@ pub struct UpdateConfig<'info> {
         constraint = authority.key() == global.authority.key()
     )]
     pub authority: Signer<'info>,
+
+    /// Mint of the new payment mint, only if it is set to an SPL token.
+    pub payment_mint: Option<Account<'info, Mint>>,
 }
 
 pub fn update_config_handler(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
@@ -44,16 +51,45 @@ pub fn update_config_handler(ctx: Context<UpdateConfig>, params: UpdateConfigPar
         None => (),
     }
 
-    match params.treasury {
-        Some(treasury) => {
-            global.treasury = treasury.key();
+    match params.provider_cooldown {
+        Some(provider_cooldown) => {
+            global.provider_cooldown = provider_cooldown;
         }
         None => (),
     }
 
-    match params.authority {
-        Some(authority) => {
-            global.authority = authority.key();
+    match params.payment_mint {
+        Some(payment_mint) => {
+            if !is_valid_payment_mint(&payment_mint, ctx.accounts.payment_mint.as_ref()) {
+                return Err(error!(CustomError::InvalidPaymentAccount));
+            }
+
+            global.payment_mint = payment_mint.key();
+        }
+        None => (),
+    }
+
+    match params.max_integrator_share {
+        Some(max_integrator_share) => {
+            if max_integrator_share > 10000 {
+                return Err(error!(CustomError::InvalidIntegratorShare));
+            }
+
+            global.max_integrator_share = max_integrator_share;
+        }
+        None => (),
+    }
+
+    match params.unpause_timelock {
+        Some(unpause_timelock) => {
+            global.unpause_timelock = unpause_timelock;
+        }
+        None => (),
+    }
+
+    match params.treasury {
+        Some(treasury) => {
+            global.treasury = treasury.key();
         }
         None => (),
     }
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Coupon, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateCouponParams {
+    close: Option<bool>,
+    max_redemptions: Option<u32>,
+    expires_at: Option<i64>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdateCouponParams)]
+pub struct UpdateCoupon<'info> {
+    #[account(
+        mut,
+        has_one = provider,
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    #[account(
+        mut,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 1) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn update_coupon_handler(ctx: Context<UpdateCoupon>, params: UpdateCouponParams) -> Result<()> {
+    let coupon = &mut ctx.accounts.coupon;
+
+    match params.close {
+        Some(close) => {
+            if close {
+                let source_account_info = coupon.to_account_info();
+                let dest_account_info = ctx.accounts.authority.to_account_info();
+
+                let dest_starting_lamports = dest_account_info.lamports();
+                **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+                    .checked_add(source_account_info.lamports())
+                    .unwrap();
+                **source_account_info.lamports.borrow_mut() = 0;
+
+                let mut source_data = source_account_info.data.borrow_mut();
+                source_data.fill(0);
+
+                let provider = &mut ctx.accounts.provider;
+                provider.coupon_count = provider.coupon_count.saturating_sub(1);
+
+                return Ok(());
+            }
+        }
+        None => {
+            match params.max_redemptions {
+                Some(max_redemptions) => {
+                    coupon.max_redemptions = max_redemptions;
+                }
+                None => (),
+            }
+
+            match params.expires_at {
+                Some(expires_at) => {
+                    coupon.expires_at = expires_at;
+                }
+                None => (),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::License;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateLicenseParams {
+    treasury: Option<Pubkey>,
+    registration_fee: Option<u64>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdateLicenseParams)]
+pub struct UpdateLicense<'info> {
+    #[account(
+        mut,
+        has_one = licensee,
+    )]
+    pub license: Account<'info, License>,
+
+    pub licensee: Signer<'info>,
+}
+
+pub fn update_license_handler(
+    ctx: Context<UpdateLicense>,
+    params: UpdateLicenseParams,
+) -> Result<()> {
+    let license = &mut ctx.accounts.license;
+
+    match params.treasury {
+        Some(treasury) => {
+            license.treasury = treasury.key();
+        }
+        None => (),
+    }
+
+    match params.registration_fee {
+        Some(registration_fee) => {
+            license.registration_fee = registration_fee;
+        }
+        None => (),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{License, Provider, RateLimit, Validator},
+    CustomError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateLicenseValidatorParams {
+    close: Option<bool>,
+    flags: Option<u8>,
+    max_creations: Option<u32>,
+    window_slots: Option<u64>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdateLicenseValidatorParams)]
+pub struct UpdateLicenseValidator<'info> {
+    #[account(
+        mut,
+        constraint = validator.license.key() == license.key(),
+    )]
+    pub validator: Account<'info, Validator>,
+
+    #[account(
+        mut,
+        has_one = licensee,
+        has_one = provider,
+    )]
+    pub license: Account<'info, License>,
+
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut)]
+    pub licensee: Signer<'info>,
+}
+
+pub fn update_license_validator_handler(
+    ctx: Context<UpdateLicenseValidator>,
+    params: UpdateLicenseValidatorParams,
+) -> Result<()> {
+    let provider = &ctx.accounts.provider;
+    let license = &mut ctx.accounts.license;
+    let validator = &mut ctx.accounts.validator;
+
+    // while paused (512), validators can only be closed or stripped of flags, eg. to disable a leaked one
+    if provider.flags & 512 == 512 {
+        let is_restriction = params.close.is_none()
+            && params.max_creations.is_none()
+            && params.window_slots.is_none()
+            && params
+                .flags
+                .is_some_and(|flags| flags & !validator.flags == 0);
+
+        if params.close != Some(true) && !is_restriction {
+            return Err(error!(CustomError::ProviderPaused));
+        }
+    }
+
+    match params.close {
+        Some(close) => {
+            if close {
+                let source_account_info = validator.to_account_info();
+                let dest_account_info = ctx.accounts.licensee.to_account_info();
+
+                let dest_starting_lamports = dest_account_info.lamports();
+                **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+                    .checked_add(source_account_info.lamports())
+                    .unwrap();
+                **source_account_info.lamports.borrow_mut() = 0;
+
+                let mut source_data = source_account_info.data.borrow_mut();
+                source_data.fill(0);
+
+                license.validator_count -= 1;
+
+                return Ok(());
+            }
+        }
+        None => match params.flags {
+            Some(flags) => {
+                // voids (4) are only permitted to the provider's own validators
+                validator.flags = flags & 3;
+            }
+            None => (),
+        },
+    }
+
+    match params.max_creations {
+        Some(max_creations) => {
+            validator.rate_limit.max_creations = max_creations;
+        }
+        None => (),
+    }
+
+    match params.window_slots {
+        Some(window_slots) => {
+            validator.rate_limit.window_slots = window_slots;
+        }
+        None => (),
+    }
+
+    if !RateLimit::is_valid(
+        validator.rate_limit.max_creations,
+        validator.rate_limit.window_slots,
+    ) {
+        return Err(error!(CustomError::InvalidRateLimit));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Multisig, CustomError};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateMultisigParams {
+    members: Option<Vec<Pubkey>>,
+    threshold: Option<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdateMultisigParams)]
+pub struct UpdateMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "multisig".as_bytes(),
+            multisig.create_key.as_ref(),
+        ],
+        bump = multisig.bump,
+        realloc = Multisig::len(params.members.as_ref().unwrap_or(&multisig.members)),
+        realloc::payer = multisig_signer,
+        realloc::zero = false,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            "multisig_signer".as_bytes(),
+            multisig.key().as_ref(),
+        ],
+        bump = multisig.signer_bump,
+    )]
+    pub multisig_signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Can only be invoked by the multisig itself through an executed proposal.
+pub fn update_multisig_handler(
+    ctx: Context<UpdateMultisig>,
+    params: UpdateMultisigParams,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+
+    match params.members {
+        Some(members) => {
+            multisig.members = members;
+        }
+        None => (),
+    }
+
+    match params.threshold {
+        Some(threshold) => {
+            multisig.threshold = threshold;
+        }
+        None => (),
+    }
+
+    if !Multisig::is_valid(&multisig.members, multisig.threshold) {
+        return Err(error!(CustomError::InvalidMultisig));
+    }
+
+    Ok(())
+}
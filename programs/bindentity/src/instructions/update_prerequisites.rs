@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Prerequisites, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdatePrerequisitesParams {
+    providers: Vec<Pubkey>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdatePrerequisitesParams)]
+pub struct UpdatePrerequisites<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "prerequisites".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = prerequisites.bump,
+        realloc = Prerequisites::len(&params.providers),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub prerequisites: Account<'info, Prerequisites>,
+
+    #[account(
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 4) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces all of the required providers, resizing the account to fit them.
+pub fn update_prerequisites_handler(
+    ctx: Context<UpdatePrerequisites>,
+    params: UpdatePrerequisitesParams,
+) -> Result<()> {
+    if !Prerequisites::is_valid(&ctx.accounts.provider.key(), &params.providers) {
+        return Err(error!(CustomError::InvalidPrerequisites));
+    }
+
+    ctx.accounts.prerequisites.providers = params.providers;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{HolderDiscount, PricingRules, Provider, ProviderDelegate},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdatePricingRulesParams {
+    renewal_discount: u16,
+    holder_discounts: Vec<HolderDiscount>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdatePricingRulesParams)]
+pub struct UpdatePricingRules<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "pricing_rules".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = pricing_rules.bump,
+        realloc = PricingRules::len(&params.holder_discounts),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub pricing_rules: Account<'info, PricingRules>,
+
+    #[account(
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 1) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces all of the pricing rules, resizing the account to fit the new holder discounts.
+pub fn update_pricing_rules_handler(
+    ctx: Context<UpdatePricingRules>,
+    params: UpdatePricingRulesParams,
+) -> Result<()> {
+    let pricing_rules = &mut ctx.accounts.pricing_rules;
+
+    if !PricingRules::is_valid(params.renewal_discount, &params.holder_discounts) {
+        return Err(error!(CustomError::InvalidPricingRules));
+    }
+
+    pricing_rules.renewal_discount = params.renewal_discount;
+    pricing_rules.holder_discounts = params.holder_discounts;
+
+    Ok(())
+}
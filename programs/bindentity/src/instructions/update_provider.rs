@@ -1,25 +1,45 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
 
-use crate::{state::Provider, CustomError};
+use crate::{
+    state::{DataSchema, Provider, ProviderDelegate, RateLimit},
+    utils::is_valid_payment_mint,
+    CustomError,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateProviderParams {
     for_sale: Option<bool>,
     published: Option<bool>,
     selling_price: Option<u64>,
-    authority: Option<Pubkey>,
     treasury: Option<Pubkey>,
     registration_fee: Option<u64>,
+    payment_mint: Option<Pubkey>,
+    min_custom_fee: Option<u64>,
+    max_custom_fee: Option<u64>,
+    refund_window: Option<i64>,
+    refund_share: Option<u16>,
+    subscription_period: Option<i64>,
+    data_schema: Option<DataSchema>,
+    max_creations: Option<u32>,
+    window_slots: Option<u64>,
+    guardian: Option<Pubkey>,
 }
 
 #[derive(Accounts)]
 #[instruction(params: UpdateProviderParams)]
 pub struct UpdateProvider<'info> {
+    #[account(mut)]
+    pub provider: Account<'info, Provider>,
+
     #[account(
-        mut,
-        has_one = authority,
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
     )]
-    pub provider: Account<'info, Provider>,
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    /// Mint of the new payment mint, only if it is set to an SPL token.
+    pub payment_mint: Option<Account<'info, Mint>>,
 
     pub authority: Signer<'info>,
 }
@@ -28,8 +48,62 @@ pub fn update_provider_handler(
     ctx: Context<UpdateProvider>,
     params: UpdateProviderParams,
 ) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let delegate = ctx.accounts.delegate.as_deref();
     let provider = &mut ctx.accounts.provider;
 
+    // listing requires the sales manager role (8)
+    if (params.for_sale.is_some() || params.selling_price.is_some())
+        && !provider.has_role(&authority.key(), delegate, 8)
+    {
+        return Err(error!(CustomError::RoleUnauthorized));
+    }
+
+    // publishing and the data schema require the metadata manager role (4)
+    if (params.published.is_some() || params.data_schema.is_some())
+        && !provider.has_role(&authority.key(), delegate, 4)
+    {
+        return Err(error!(CustomError::RoleUnauthorized));
+    }
+
+    // the guardian can only be designated by the authority
+    if params.guardian.is_some() && provider.authority.key() != authority.key() {
+        return Err(error!(CustomError::RoleUnauthorized));
+    }
+
+    // the provider cannot be listed while paused (512), the authority can still replace a compromised guardian
+    if params.for_sale == Some(true) && provider.flags & 512 == 512 {
+        return Err(error!(CustomError::ProviderPaused));
+    }
+
+    // rate limits require the validator manager role (2)
+    if (params.max_creations.is_some() || params.window_slots.is_some())
+        && !provider.has_role(&authority.key(), delegate, 2)
+    {
+        return Err(error!(CustomError::RoleUnauthorized));
+    }
+
+    // fees require the fee manager role (1)
+    if (params.treasury.is_some()
+        || params.registration_fee.is_some()
+        || params.payment_mint.is_some()
+        || params.min_custom_fee.is_some()
+        || params.max_custom_fee.is_some()
+        || params.refund_window.is_some()
+        || params.refund_share.is_some()
+        || params.subscription_period.is_some())
+        && !provider.has_role(&authority.key(), delegate, 1)
+    {
+        return Err(error!(CustomError::RoleUnauthorized));
+    }
+
+    // the auction keeps the provider listed until it is settled
+    if (params.for_sale.is_some() || params.selling_price.is_some())
+        && provider.flags & 1024 == 1024
+    {
+        return Err(error!(CustomError::SellingNotAllowed));
+    }
+
     match params.for_sale {
         Some(for_sale) => {
             if for_sale {
@@ -39,6 +113,8 @@ pub fn update_provider_handler(
                     || provider.flags & 2 == 2 
                     // provider should have no validators
                     || provider.validator_count != 0 
+                    // provider should have no licenses
+                    || provider.license_count != 0
                 {
                     return Err(error!(CustomError::SellingNotAllowed));
                 }
@@ -75,13 +151,45 @@ pub fn update_provider_handler(
         None => (),
     }
 
-    match params.authority {
-        Some(authority) => {
-            provider.authority = authority;
+    match params.data_schema {
+        Some(data_schema) => {
+            if !data_schema.is_valid() {
+                return Err(error!(CustomError::InvalidDataSchema));
+            }
+
+            provider.data_schema = data_schema;
+        }
+        None => (),
+    }
+
+    match params.guardian {
+        Some(guardian) => {
+            provider.guardian = guardian.key();
+        }
+        None => (),
+    }
+
+    match params.max_creations {
+        Some(max_creations) => {
+            provider.rate_limit.max_creations = max_creations;
+        }
+        None => (),
+    }
+
+    match params.window_slots {
+        Some(window_slots) => {
+            provider.rate_limit.window_slots = window_slots;
         }
         None => (),
     }
 
+    if !RateLimit::is_valid(
+        provider.rate_limit.max_creations,
+        provider.rate_limit.window_slots,
+    ) {
+        return Err(error!(CustomError::InvalidRateLimit));
+    }
+
     match params.treasury {
         Some(treasury) => {
             provider.treasury = treasury;
@@ -96,5 +204,74 @@ pub fn update_provider_handler(
         None => (),
     }
 
+    match params.payment_mint {
+        Some(payment_mint) => {
+            if !is_valid_payment_mint(&payment_mint, ctx.accounts.payment_mint.as_ref()) {
+                return Err(error!(CustomError::InvalidPaymentAccount));
+            }
+
+            provider.payment_mint = payment_mint.key();
+        }
+        None => (),
+    }
+
+    match params.min_custom_fee {
+        Some(min_custom_fee) => {
+            provider.min_custom_fee = min_custom_fee;
+        }
+        None => (),
+    }
+
+    match params.max_custom_fee {
+        Some(max_custom_fee) => {
+            provider.max_custom_fee = max_custom_fee;
+        }
+        None => (),
+    }
+
+    if provider.min_custom_fee > provider.max_custom_fee {
+        return Err(error!(CustomError::InvalidFeeBounds));
+    }
+
+    match params.refund_window {
+        Some(refund_window) => {
+            provider.refund_window = refund_window;
+        }
+        None => (),
+    }
+
+    match params.refund_share {
+        Some(refund_share) => {
+            provider.refund_share = refund_share;
+        }
+        None => (),
+    }
+
+    match params.subscription_period {
+        Some(subscription_period) => {
+            provider.subscription_period = subscription_period;
+        }
+        None => (),
+    }
+
+    // the fee of a subscription is not split with licensees
+    if provider.subscription_period < 0
+        || (provider.subscription_period > 0
+            && (provider.payment_mint.key() != Pubkey::default() || provider.license_count > 0))
+    {
+        return Err(error!(CustomError::InvalidSubscription));
+    }
+
+    // escrows only hold native SOL, and are not split between licensees or revenue recipients
+    if provider.refund_window < 0
+        || provider.refund_share > 10000
+        || (provider.refund_window > 0
+            && (provider.payment_mint.key() != Pubkey::default()
+                || provider.flags & 64 == 64
+                || provider.license_count > 0))
+    {
+        return Err(error!(CustomError::InvalidRefundPolicy));
+    }
+
     Ok(())
 }
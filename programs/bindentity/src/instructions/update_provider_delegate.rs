@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Provider, ProviderDelegate};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateProviderDelegateParams {
+    close: Option<bool>,
+    roles: Option<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdateProviderDelegateParams)]
+pub struct UpdateProviderDelegate<'info> {
+    #[account(
+        mut,
+        has_one = provider,
+    )]
+    pub provider_delegate: Account<'info, ProviderDelegate>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn update_provider_delegate_handler(
+    ctx: Context<UpdateProviderDelegate>,
+    params: UpdateProviderDelegateParams,
+) -> Result<()> {
+    let provider_delegate = &mut ctx.accounts.provider_delegate;
+
+    match params.close {
+        Some(close) => {
+            if close {
+                let source_account_info = provider_delegate.to_account_info();
+                let dest_account_info = ctx.accounts.authority.to_account_info();
+
+                let dest_starting_lamports = dest_account_info.lamports();
+                **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+                    .checked_add(source_account_info.lamports())
+                    .unwrap();
+                **source_account_info.lamports.borrow_mut() = 0;
+
+                let mut source_data = source_account_info.data.borrow_mut();
+                source_data.fill(0);
+
+                let provider = &mut ctx.accounts.provider;
+                provider.delegate_count = provider.delegate_count.saturating_sub(1);
+
+                return Ok(());
+            }
+        }
+        None => match params.roles {
+            Some(roles) => {
+                // granted again by the current authority
+                provider_delegate.roles = roles;
+                provider_delegate.authority_epoch = ctx.accounts.provider.authority_epoch;
+            }
+            None => (),
+        },
+    }
+
+    Ok(())
+}
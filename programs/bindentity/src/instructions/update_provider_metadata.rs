@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Provider, ProviderDelegate, ProviderMetadata},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateProviderMetadataParams {
+    uri: String,
+    content_hash: Option<[u8; 32]>,
+    display_name: String,
+    registration_url: String,
+    tags: Vec<String>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdateProviderMetadataParams)]
+pub struct UpdateProviderMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "provider_metadata".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = provider_metadata.bump,
+        realloc = ProviderMetadata::len(
+            &params.uri,
+            &params.display_name,
+            &params.registration_url,
+            &params.tags,
+        ),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub provider_metadata: Account<'info, ProviderMetadata>,
+
+    #[account(
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 4) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces all of the metadata fields, resizing the account to fit the new values.
+pub fn update_provider_metadata_handler(
+    ctx: Context<UpdateProviderMetadata>,
+    params: UpdateProviderMetadataParams,
+) -> Result<()> {
+    let metadata = &mut ctx.accounts.provider_metadata;
+
+    metadata.uri = params.uri;
+    metadata.content_hash = params.content_hash;
+    metadata.display_name = params.display_name;
+    metadata.registration_url = params.registration_url;
+    metadata.tags = params.tags;
+
+    Ok(())
+}
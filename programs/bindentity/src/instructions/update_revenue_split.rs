@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Provider, ProviderDelegate, RevenueSplit, SplitRecipient},
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateRevenueSplitParams {
+    recipients: Vec<SplitRecipient>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdateRevenueSplitParams)]
+pub struct UpdateRevenueSplit<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "revenue_split".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = revenue_split.bump,
+        realloc = RevenueSplit::len(&params.recipients),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+
+    #[account(
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 1) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces all of the recipients, resizing the account to fit them.
+pub fn update_revenue_split_handler(
+    ctx: Context<UpdateRevenueSplit>,
+    params: UpdateRevenueSplitParams,
+) -> Result<()> {
+    if !RevenueSplit::is_valid(&params.recipients) {
+        return Err(error!(CustomError::InvalidRevenueSplit));
+    }
+
+    ctx.accounts.revenue_split.recipients = params.recipients;
+
+    Ok(())
+}
@@ -1,11 +1,16 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Provider, Validator};
+use crate::{
+    state::{Provider, ProviderDelegate, RateLimit, Validator},
+    CustomError,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateValidatorParams {
     close: Option<bool>,
     flags: Option<u8>,
+    max_creations: Option<u32>,
+    window_slots: Option<u64>,
 }
 
 #[derive(Accounts)]
@@ -14,15 +19,22 @@ pub struct UpdateValidator<'info> {
     #[account(
         mut,
         constraint = validator.provider.key() == provider.key(),
+        constraint = validator.license.key() == Pubkey::default(),
     )]
     pub validator: Account<'info, Validator>,
 
     #[account(
         mut,
-        has_one = authority,
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 2) @ CustomError::RoleUnauthorized,
     )]
     pub provider: Account<'info, Provider>,
 
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 }
@@ -34,6 +46,20 @@ pub fn update_validator_handler(
     let provider = &mut ctx.accounts.provider;
     let validator = &mut ctx.accounts.validator;
 
+    // while paused (512), validators can only be closed or stripped of flags, eg. to disable a leaked one
+    if provider.flags & 512 == 512 {
+        let is_restriction = params.close.is_none()
+            && params.max_creations.is_none()
+            && params.window_slots.is_none()
+            && params
+                .flags
+                .is_some_and(|flags| flags & !validator.flags == 0);
+
+        if params.close != Some(true) && !is_restriction {
+            return Err(error!(CustomError::ProviderPaused));
+        }
+    }
+
     match params.close {
         Some(close) => {
             if close {
@@ -67,5 +93,26 @@ pub fn update_validator_handler(
         },
     }
 
+    match params.max_creations {
+        Some(max_creations) => {
+            validator.rate_limit.max_creations = max_creations;
+        }
+        None => (),
+    }
+
+    match params.window_slots {
+        Some(window_slots) => {
+            validator.rate_limit.window_slots = window_slots;
+        }
+        None => (),
+    }
+
+    if !RateLimit::is_valid(
+        validator.rate_limit.max_creations,
+        validator.rate_limit.window_slots,
+    ) {
+        return Err(error!(CustomError::InvalidRateLimit));
+    }
+
     Ok(())
 }
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Bindie, Link, Provider, Validator};
+use crate::{
+    state::{Bindie, Link, Provider, Validator},
+    CustomError,
+};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct VerifyProviderParams {
@@ -37,7 +40,8 @@ pub struct VerifyProvider<'info> {
             verifier_provider.key().as_ref(),
             Bindie::crop(&params.data).as_bytes(),
         ],
-        bump = owner_bindie.bump
+        bump = owner_bindie.bump,
+        constraint = owner_bindie.is_active(Clock::get()?.unix_timestamp) @ CustomError::SubscriptionLapsed,
     )]
     pub owner_bindie: Account<'info, Bindie>,
 
@@ -62,6 +66,7 @@ pub fn verify_provider_handler(
     let target_provider = &mut ctx.accounts.target_provider;
 
     target_provider.flags |= 4;
+    target_provider.verified_bindie = ctx.accounts.owner_bindie.key();
 
     Ok(())
 }
@@ -1,7 +1,9 @@
-use anchor_lang::{prelude::*, system_program};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 
 use crate::{
-    state::{Bindie, Global, Link, Provider, Validator},
+    state::{Bindie, FeeEscrow, FeeVault, Global, License, Link, Provider, Validator},
+    utils::transfer_fee,
     CustomError,
 };
 
@@ -33,18 +35,29 @@ pub struct VoidBindie<'info> {
     pub validator_signer: Signer<'info>,
 
     #[account(
+        mut,
         constraint = validator.provider.key() == provider.key(),
     )]
     pub validator: Box<Account<'info, Validator>>,
 
+    /// Required if the validator belongs to a license.
+    pub license: Option<Box<Account<'info, License>>>,
+
+    #[account(
+        mut,
+        constraint = provider.flags & 512 != 512 @ CustomError::ProviderPaused,
+    )]
     pub provider: Box<Account<'info, Provider>>,
 
     #[account(
         mut,
-        constraint = treasury.key() == global.treasury.key()
+        seeds = [
+            "vault".as_bytes(),
+            global.key().as_ref(),
+        ],
+        bump = vault.bump,
     )]
-    /// CHECK: constraint to global treasury
-    pub treasury: UncheckedAccount<'info>,
+    pub vault: Box<Account<'info, FeeVault>>,
 
     #[account(
         seeds = ["global".as_bytes()],
@@ -52,6 +65,46 @@ pub struct VoidBindie<'info> {
     )]
     pub global: Account<'info, Global>,
 
+    /// Signer's token account in the global payment mint, only if the service fee is paid in SPL token.
+    #[account(mut)]
+    pub signer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Associated token account of the protocol vault, only if the service fee is paid in SPL token.
+    #[account(mut)]
+    pub vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Escrow of the registration fee, settled if it has not been released to the provider yet.
+    #[account(
+        mut,
+        seeds = [
+            "escrow".as_bytes(),
+            bindie.key().as_ref(),
+        ],
+        bump,
+    )]
+    /// CHECK: may not exist, deserialized as a fee escrow if it does
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Required if the escrow exists.
+    #[account(
+        mut,
+        seeds = [
+            "vault".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = provider_vault.bump,
+    )]
+    pub provider_vault: Option<Box<Account<'info, FeeVault>>>,
+
+    #[account(
+        mut,
+        constraint = bindie_owner.key() == bindie.owner.key(),
+    )]
+    /// CHECK: constraint to the bindie's owner, required if the escrow exists to receive the refund
+    pub bindie_owner: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -60,11 +113,23 @@ pub struct VoidBindie<'info> {
 /// 2. If the permitted validator checked that indeed the user is the owner of the ID
 pub fn void_bindie_handler(ctx: Context<VoidBindie>, params: VoidBindieParams) -> Result<()> {
     let bindie = &ctx.accounts.bindie;
-    let provider = &ctx.accounts.provider;
-    let validator = &ctx.accounts.validator;
+    let provider = &mut ctx.accounts.provider;
+    let validator = &mut ctx.accounts.validator;
     let link = &mut ctx.accounts.link;
     let signer = &mut ctx.accounts.signer;
 
+    let is_validator_void = params.data.is_some();
+
+    // validators of a license can only be used while the license is live
+    if validator.license.key() != Pubkey::default() {
+        match ctx.accounts.license.as_ref() {
+            Some(license)
+                if license.key() == validator.license.key()
+                    && license.is_active(Clock::get()?.unix_timestamp) => {}
+            _ => return Err(error!(CustomError::InvalidLicense)),
+        }
+    }
+
     match params.data {
         Some(data) => {
             if validator.flags & 4 != 4 {
@@ -86,15 +151,62 @@ pub fn void_bindie_handler(ctx: Context<VoidBindie>, params: VoidBindieParams) -
         }
     }
 
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: signer.to_account_info(),
-            to: ctx.accounts.treasury.to_account_info(),
-        },
-    );
+    let token_program = ctx
+        .accounts
+        .token_program
+        .as_ref()
+        .map(|token_program| token_program.to_account_info());
+
+    transfer_fee(
+        &ctx.accounts.global.payment_mint,
+        ctx.accounts.global.service_fee,
+        &signer.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        ctx.accounts
+            .signer_token_account
+            .as_deref()
+            .zip(ctx.accounts.vault_token_account.as_deref()),
+        &ctx.accounts.system_program.to_account_info(),
+        token_program.as_ref(),
+    )?;
+
+    // refund part of the registration fee if voided within the refund window
+    let escrow_info = ctx.accounts.escrow.to_account_info();
+
+    if escrow_info.owner == ctx.program_id && !escrow_info.data_is_empty() {
+        let escrow = Account::<FeeEscrow>::try_from(&escrow_info)?;
+
+        let (provider_vault, bindie_owner) = match (
+            ctx.accounts.provider_vault.as_ref(),
+            ctx.accounts.bindie_owner.as_ref(),
+        ) {
+            (Some(provider_vault), Some(bindie_owner)) => (provider_vault, bindie_owner),
+            _ => return Err(error!(CustomError::MissingEscrowAccount)),
+        };
+
+        let refund = if Clock::get()?.unix_timestamp < escrow.release_at {
+            (escrow.amount as u128 * escrow.refund_share as u128 / 10000) as u64
+        } else {
+            0
+        };
+
+        provider.collected_fees = provider.collected_fees.saturating_sub(refund);
+
+        FeeEscrow::settle(
+            &escrow,
+            refund,
+            &provider_vault.to_account_info(),
+            &bindie_owner.to_account_info(),
+        )?;
+    }
+
+    provider.live_count = provider.live_count.saturating_sub(1);
+    provider.voided_count += 1;
 
-    system_program::transfer(cpi_ctx, ctx.accounts.global.service_fee)?;
+    // only voids approved by the validator are counted towards it
+    if is_validator_void {
+        validator.voided_count += 1;
+    }
 
     let source_account_info = link.to_account_info();
     let dest_account_info = signer.to_account_info();
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Bindie, Link, Provider, Subscription},
+    CustomError,
+};
+
+#[derive(Accounts)]
+pub struct VoidLapsedBindie<'info> {
+    #[account(
+        has_one = provider,
+        has_one = owner,
+    )]
+    pub bindie: Account<'info, Bindie>,
+
+    #[account(
+        mut,
+        constraint = link.bindie.key() == bindie.key(),
+    )]
+    pub link: Account<'info, Link>,
+
+    #[account(
+        mut,
+        seeds = [
+            "subscription".as_bytes(),
+            bindie.key().as_ref(),
+        ],
+        bump,
+    )]
+    /// CHECK: subscription of the bindie, may be cancelled already
+    pub subscription: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut)]
+    /// CHECK: constraint to the bindie's owner, receives the rent of the link and the rest of the subscription
+    pub owner: UncheckedAccount<'info>,
+}
+
+/// A subscription bindie lapses once `paid_until` has passed and its subscription cannot pay for the next period.
+/// Anyone can void a lapsed bindie, the same way its owner would, so that it no longer counts as live.
+pub fn void_lapsed_bindie_handler(ctx: Context<VoidLapsedBindie>) -> Result<()> {
+    let bindie = &ctx.accounts.bindie;
+    let provider = &mut ctx.accounts.provider;
+    let subscription = &ctx.accounts.subscription;
+    let dest_account_info = ctx.accounts.owner.to_account_info();
+
+    if bindie.is_active(Clock::get()?.unix_timestamp) {
+        return Err(error!(CustomError::SubscriptionNotLapsed));
+    }
+
+    // a subscription which can still pay is collected instead
+    if subscription.owner == &crate::ID && !subscription.data_is_empty() {
+        let fee = Subscription::try_deserialize(&mut &subscription.data.borrow()[..])?.fee;
+        let rent_exempt = Rent::get()?.minimum_balance(subscription.data_len());
+
+        if subscription.lamports().saturating_sub(fee) >= rent_exempt {
+            return Err(error!(CustomError::SubscriptionNotLapsed));
+        }
+
+        let source_account_info = subscription.to_account_info();
+
+        let dest_starting_lamports = dest_account_info.lamports();
+        **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+            .checked_add(source_account_info.lamports())
+            .unwrap();
+        **source_account_info.lamports.borrow_mut() = 0;
+
+        let mut source_data = source_account_info.data.borrow_mut();
+        source_data.fill(0);
+    }
+
+    provider.live_count = provider.live_count.saturating_sub(1);
+    provider.voided_count += 1;
+
+    let source_account_info = ctx.accounts.link.to_account_info();
+
+    let dest_starting_lamports = dest_account_info.lamports();
+    **dest_account_info.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(source_account_info.lamports())
+        .unwrap();
+    **source_account_info.lamports.borrow_mut() = 0;
+
+    let mut source_data = source_account_info.data.borrow_mut();
+    source_data.fill(0);
+
+    Ok(())
+}
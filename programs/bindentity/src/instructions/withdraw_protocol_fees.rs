@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{
+    state::{FeeVault, Global},
+    utils::withdraw_fee,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct WithdrawProtocolFeesParams {
+    amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: WithdrawProtocolFeesParams)]
+pub struct WithdrawProtocolFees<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "vault".as_bytes(),
+            global.key().as_ref(),
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, FeeVault>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == global.treasury.key()
+    )]
+    /// CHECK: constraint to global treasury
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = ["global".as_bytes()],
+        bump = global.bump,
+        has_one = authority,
+    )]
+    pub global: Account<'info, Global>,
+
+    pub authority: Signer<'info>,
+
+    /// Associated token account of the vault, only if withdrawing SPL token.
+    #[account(mut)]
+    pub vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Associated token account of the global treasury, only if withdrawing SPL token.
+    #[account(mut)]
+    pub treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Withdraws the collected protocol fees to the global treasury.
+pub fn withdraw_protocol_fees_handler(
+    ctx: Context<WithdrawProtocolFees>,
+    params: WithdrawProtocolFeesParams,
+) -> Result<()> {
+    let token_program = ctx
+        .accounts
+        .token_program
+        .as_ref()
+        .map(|token_program| token_program.to_account_info());
+
+    withdraw_fee(
+        &ctx.accounts.vault,
+        params.amount,
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.accounts
+            .vault_token_account
+            .as_deref()
+            .zip(ctx.accounts.treasury_token_account.as_deref()),
+        token_program.as_ref(),
+    )
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{
+    state::{FeeVault, Provider, ProviderDelegate},
+    utils::withdraw_fee,
+    CustomError,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct WithdrawProviderFeesParams {
+    amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: WithdrawProviderFeesParams)]
+pub struct WithdrawProviderFees<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "vault".as_bytes(),
+            provider.key().as_ref(),
+        ],
+        bump = provider_vault.bump,
+    )]
+    pub provider_vault: Account<'info, FeeVault>,
+
+    #[account(
+        mut,
+        constraint = provider_treasury.key() == provider.treasury.key()
+    )]
+    /// CHECK: constraint to provider's treasury
+    pub provider_treasury: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = provider.has_role(&authority.key(), delegate.as_deref(), 1) @ CustomError::RoleUnauthorized,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        has_one = provider,
+        constraint = delegate.delegate.key() == authority.key(),
+    )]
+    pub delegate: Option<Account<'info, ProviderDelegate>>,
+
+    pub authority: Signer<'info>,
+
+    /// Associated token account of the provider vault, only if withdrawing SPL token.
+    #[account(mut)]
+    pub provider_vault_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Associated token account of the provider's treasury, only if withdrawing SPL token.
+    #[account(mut)]
+    pub provider_treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+/// Withdraws the collected registration fees to the provider's treasury.
+pub fn withdraw_provider_fees_handler(
+    ctx: Context<WithdrawProviderFees>,
+    params: WithdrawProviderFeesParams,
+) -> Result<()> {
+    let token_program = ctx
+        .accounts
+        .token_program
+        .as_ref()
+        .map(|token_program| token_program.to_account_info());
+
+    withdraw_fee(
+        &ctx.accounts.provider_vault,
+        params.amount,
+        &ctx.accounts.provider_treasury.to_account_info(),
+        ctx.accounts
+            .provider_vault_token_account
+            .as_deref()
+            .zip(ctx.accounts.provider_treasury_token_account.as_deref()),
+        token_program.as_ref(),
+    )
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 pub use instructions::*;
 
@@ -19,6 +20,21 @@ pub mod bindentity {
         update_config_handler(ctx, params)
     }
 
+    pub fn propose_config_authority(
+        ctx: Context<ProposeConfigAuthority>,
+        params: ProposeConfigAuthorityParams,
+    ) -> Result<()> {
+        propose_config_authority_handler(ctx, params)
+    }
+
+    pub fn accept_config_authority(ctx: Context<AcceptConfigAuthority>) -> Result<()> {
+        accept_config_authority_handler(ctx)
+    }
+
+    pub fn cancel_config_authority(ctx: Context<CancelConfigAuthority>) -> Result<()> {
+        cancel_config_authority_handler(ctx)
+    }
+
     pub fn create_provider(
         ctx: Context<CreateProvider>,
         params: CreateProviderParams,
@@ -26,6 +42,17 @@ pub mod bindentity {
         create_provider_handler(ctx, params)
     }
 
+    pub fn approve_sub_provider(
+        ctx: Context<ApproveSubProvider>,
+        params: ApproveSubProviderParams,
+    ) -> Result<()> {
+        approve_sub_provider_handler(ctx, params)
+    }
+
+    pub fn revoke_sub_provider(ctx: Context<RevokeSubProvider>) -> Result<()> {
+        revoke_sub_provider_handler(ctx)
+    }
+
     pub fn create_provider_metadata(
         ctx: Context<CreateProviderMetadata>,
         params: CreateProviderMetadataParams,
@@ -33,6 +60,35 @@ pub mod bindentity {
         create_provider_metadata_handler(ctx, params)
     }
 
+    pub fn update_provider_metadata(
+        ctx: Context<UpdateProviderMetadata>,
+        params: UpdateProviderMetadataParams,
+    ) -> Result<()> {
+        update_provider_metadata_handler(ctx, params)
+    }
+
+    pub fn remove_provider_metadata(ctx: Context<RemoveProviderMetadata>) -> Result<()> {
+        remove_provider_metadata_handler(ctx)
+    }
+
+    pub fn close_provider(ctx: Context<CloseProvider>) -> Result<()> {
+        close_provider_handler(ctx)
+    }
+
+    pub fn create_provider_delegate(
+        ctx: Context<CreateProviderDelegate>,
+        params: CreateProviderDelegateParams,
+    ) -> Result<()> {
+        create_provider_delegate_handler(ctx, params)
+    }
+
+    pub fn update_provider_delegate(
+        ctx: Context<UpdateProviderDelegate>,
+        params: UpdateProviderDelegateParams,
+    ) -> Result<()> {
+        update_provider_delegate_handler(ctx, params)
+    }
+
     pub fn create_validator(
         ctx: Context<CreateValidator>,
         params: CreateValidatorParams,
@@ -40,10 +96,149 @@ pub mod bindentity {
         create_validator_handler(ctx, params)
     }
 
-    pub fn create_bindie(ctx: Context<CreateBindie>, params: CreateBindieParams) -> Result<()> {
+    pub fn create_pricing_rules(
+        ctx: Context<CreatePricingRules>,
+        params: CreatePricingRulesParams,
+    ) -> Result<()> {
+        create_pricing_rules_handler(ctx, params)
+    }
+
+    pub fn update_pricing_rules(
+        ctx: Context<UpdatePricingRules>,
+        params: UpdatePricingRulesParams,
+    ) -> Result<()> {
+        update_pricing_rules_handler(ctx, params)
+    }
+
+    pub fn remove_pricing_rules(ctx: Context<RemovePricingRules>) -> Result<()> {
+        remove_pricing_rules_handler(ctx)
+    }
+
+    pub fn create_coupon(ctx: Context<CreateCoupon>, params: CreateCouponParams) -> Result<()> {
+        create_coupon_handler(ctx, params)
+    }
+
+    pub fn update_coupon(ctx: Context<UpdateCoupon>, params: UpdateCouponParams) -> Result<()> {
+        update_coupon_handler(ctx, params)
+    }
+
+    pub fn create_bindie<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBindie<'info>>,
+        params: CreateBindieParams,
+    ) -> Result<()> {
         create_bindie_handler(ctx, params)
     }
 
+    pub fn create_revenue_split(
+        ctx: Context<CreateRevenueSplit>,
+        params: CreateRevenueSplitParams,
+    ) -> Result<()> {
+        create_revenue_split_handler(ctx, params)
+    }
+
+    pub fn update_revenue_split(
+        ctx: Context<UpdateRevenueSplit>,
+        params: UpdateRevenueSplitParams,
+    ) -> Result<()> {
+        update_revenue_split_handler(ctx, params)
+    }
+
+    pub fn remove_revenue_split(ctx: Context<RemoveRevenueSplit>) -> Result<()> {
+        remove_revenue_split_handler(ctx)
+    }
+
+    pub fn create_prerequisites(
+        ctx: Context<CreatePrerequisites>,
+        params: CreatePrerequisitesParams,
+    ) -> Result<()> {
+        create_prerequisites_handler(ctx, params)
+    }
+
+    pub fn update_prerequisites(
+        ctx: Context<UpdatePrerequisites>,
+        params: UpdatePrerequisitesParams,
+    ) -> Result<()> {
+        update_prerequisites_handler(ctx, params)
+    }
+
+    pub fn remove_prerequisites(ctx: Context<RemovePrerequisites>) -> Result<()> {
+        remove_prerequisites_handler(ctx)
+    }
+
+    pub fn create_access_list(
+        ctx: Context<CreateAccessList>,
+        params: CreateAccessListParams,
+    ) -> Result<()> {
+        create_access_list_handler(ctx, params)
+    }
+
+    pub fn update_access_list(
+        ctx: Context<UpdateAccessList>,
+        params: UpdateAccessListParams,
+    ) -> Result<()> {
+        update_access_list_handler(ctx, params)
+    }
+
+    pub fn remove_access_list(ctx: Context<RemoveAccessList>) -> Result<()> {
+        remove_access_list_handler(ctx)
+    }
+
+    pub fn create_wallet_entry(
+        ctx: Context<CreateWalletEntry>,
+        params: CreateWalletEntryParams,
+    ) -> Result<()> {
+        create_wallet_entry_handler(ctx, params)
+    }
+
+    pub fn remove_wallet_entry(ctx: Context<RemoveWalletEntry>) -> Result<()> {
+        remove_wallet_entry_handler(ctx)
+    }
+
+    pub fn create_vault(ctx: Context<CreateVault>) -> Result<()> {
+        create_vault_handler(ctx)
+    }
+
+    pub fn create_provider_vault(ctx: Context<CreateProviderVault>) -> Result<()> {
+        create_provider_vault_handler(ctx)
+    }
+
+    pub fn withdraw_protocol_fees(
+        ctx: Context<WithdrawProtocolFees>,
+        params: WithdrawProtocolFeesParams,
+    ) -> Result<()> {
+        withdraw_protocol_fees_handler(ctx, params)
+    }
+
+    pub fn withdraw_provider_fees(
+        ctx: Context<WithdrawProviderFees>,
+        params: WithdrawProviderFeesParams,
+    ) -> Result<()> {
+        withdraw_provider_fees_handler(ctx, params)
+    }
+
+    pub fn release_escrow(ctx: Context<ReleaseEscrow>) -> Result<()> {
+        release_escrow_handler(ctx)
+    }
+
+    pub fn create_subscription(
+        ctx: Context<CreateSubscription>,
+        params: CreateSubscriptionParams,
+    ) -> Result<()> {
+        create_subscription_handler(ctx, params)
+    }
+
+    pub fn collect_subscription(ctx: Context<CollectSubscription>) -> Result<()> {
+        collect_subscription_handler(ctx)
+    }
+
+    pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+        cancel_subscription_handler(ctx)
+    }
+
+    pub fn void_lapsed_bindie(ctx: Context<VoidLapsedBindie>) -> Result<()> {
+        void_lapsed_bindie_handler(ctx)
+    }
+
     pub fn verify_provider(
         ctx: Context<VerifyProvider>,
         params: VerifyProviderParams,
@@ -51,6 +246,13 @@ pub mod bindentity {
         verify_provider_handler(ctx, params)
     }
 
+    pub fn unverify_provider(
+        ctx: Context<UnverifyProvider>,
+        params: UnverifyProviderParams,
+    ) -> Result<()> {
+        unverify_provider_handler(ctx, params)
+    }
+
     pub fn update_provider(
         ctx: Context<UpdateProvider>,
         params: UpdateProviderParams,
@@ -58,10 +260,91 @@ pub mod bindentity {
         update_provider_handler(ctx, params)
     }
 
+    pub fn pause_provider(ctx: Context<PauseProvider>) -> Result<()> {
+        pause_provider_handler(ctx)
+    }
+
+    pub fn request_unpause_provider(ctx: Context<RequestUnpauseProvider>) -> Result<()> {
+        request_unpause_provider_handler(ctx)
+    }
+
+    pub fn unpause_provider(ctx: Context<UnpauseProvider>) -> Result<()> {
+        unpause_provider_handler(ctx)
+    }
+
+    pub fn propose_provider_authority(
+        ctx: Context<ProposeProviderAuthority>,
+        params: ProposeProviderAuthorityParams,
+    ) -> Result<()> {
+        propose_provider_authority_handler(ctx, params)
+    }
+
+    pub fn accept_provider_authority(ctx: Context<AcceptProviderAuthority>) -> Result<()> {
+        accept_provider_authority_handler(ctx)
+    }
+
+    pub fn cancel_provider_authority(ctx: Context<CancelProviderAuthority>) -> Result<()> {
+        cancel_provider_authority_handler(ctx)
+    }
+
+    pub fn grant_license(ctx: Context<GrantLicense>, params: GrantLicenseParams) -> Result<()> {
+        grant_license_handler(ctx, params)
+    }
+
+    pub fn update_license(ctx: Context<UpdateLicense>, params: UpdateLicenseParams) -> Result<()> {
+        update_license_handler(ctx, params)
+    }
+
+    pub fn revoke_license(ctx: Context<RevokeLicense>) -> Result<()> {
+        revoke_license_handler(ctx)
+    }
+
+    pub fn close_license_validators(ctx: Context<CloseLicenseValidators>) -> Result<()> {
+        close_license_validators_handler(ctx)
+    }
+
+    pub fn create_license_validator(
+        ctx: Context<CreateLicenseValidator>,
+        params: CreateLicenseValidatorParams,
+    ) -> Result<()> {
+        create_license_validator_handler(ctx, params)
+    }
+
+    pub fn update_license_validator(
+        ctx: Context<UpdateLicenseValidator>,
+        params: UpdateLicenseValidatorParams,
+    ) -> Result<()> {
+        update_license_validator_handler(ctx, params)
+    }
+
     pub fn buy_provider(ctx: Context<BuyProvider>, params: BuyProviderParams) -> Result<()> {
         buy_provider_handler(ctx, params)
     }
 
+    pub fn start_auction(ctx: Context<StartAuction>, params: StartAuctionParams) -> Result<()> {
+        start_auction_handler(ctx, params)
+    }
+
+    pub fn place_bid(ctx: Context<PlaceBid>, params: PlaceBidParams) -> Result<()> {
+        place_bid_handler(ctx, params)
+    }
+
+    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+        settle_auction_handler(ctx)
+    }
+
+    pub fn place_offer(ctx: Context<PlaceOffer>, params: PlaceOfferParams) -> Result<()> {
+        place_offer_handler(ctx, params)
+    }
+
+    pub fn accept_offer(ctx: Context<AcceptOffer>, params: AcceptOfferParams) -> Result<()> {
+        accept_offer_handler(ctx, params)
+    }
+
+    pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+        cancel_offer_handler(ctx)
+    }
+
     pub fn update_validator(
         ctx: Context<UpdateValidator>,
         params: UpdateValidatorParams,
@@ -72,6 +355,61 @@ pub mod bindentity {
     pub fn void_bindie(ctx: Context<VoidBindie>, params: VoidBindieParams) -> Result<()> {
         void_bindie_handler(ctx, params)
     }
+
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        params: CreateMultisigParams,
+    ) -> Result<()> {
+        create_multisig_handler(ctx, params)
+    }
+
+    pub fn update_multisig(
+        ctx: Context<UpdateMultisig>,
+        params: UpdateMultisigParams,
+    ) -> Result<()> {
+        update_multisig_handler(ctx, params)
+    }
+
+    pub fn create_multisig_proposal(
+        ctx: Context<CreateMultisigProposal>,
+        params: CreateMultisigProposalParams,
+    ) -> Result<()> {
+        create_multisig_proposal_handler(ctx, params)
+    }
+
+    pub fn approve_multisig_proposal(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+        approve_multisig_proposal_handler(ctx)
+    }
+
+    pub fn execute_multisig_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteMultisigProposal<'info>>,
+    ) -> Result<()> {
+        execute_multisig_proposal_handler(ctx)
+    }
+
+    pub fn migrate_global(ctx: Context<MigrateGlobal>) -> Result<()> {
+        migrate_global_handler(ctx)
+    }
+
+    pub fn migrate_provider(ctx: Context<MigrateProvider>) -> Result<()> {
+        migrate_provider_handler(ctx)
+    }
+
+    pub fn migrate_provider_metadata(ctx: Context<MigrateProviderMetadata>) -> Result<()> {
+        migrate_provider_metadata_handler(ctx)
+    }
+
+    pub fn migrate_validator(ctx: Context<MigrateValidator>) -> Result<()> {
+        migrate_validator_handler(ctx)
+    }
+
+    pub fn migrate_link(ctx: Context<MigrateLink>) -> Result<()> {
+        migrate_link_handler(ctx)
+    }
+
+    pub fn migrate_bindie(ctx: Context<MigrateBindie>) -> Result<()> {
+        migrate_bindie_handler(ctx)
+    }
 }
 
 #[error_code]
@@ -93,4 +431,159 @@ pub enum CustomError {
 
     #[msg("Cannot sell a disabled / published / provider with validators")]
     SellingNotAllowed,
+
+    #[msg("Signers are not authorized to unverify the provider")]
+    UnverifyUnauthorized,
+
+    #[msg("Bindie backing the provider verification is still valid")]
+    VerificationStillValid,
+
+    #[msg("Cannot close a provider with validators / live bindies / children / listed for sale")]
+    ClosingNotAllowed,
+
+    #[msg("Provider name is still reserved for its previous authority")]
+    ProviderCoolingDown,
+
+    #[msg("Multisig members should be unique and the threshold should be within the number of members")]
+    InvalidMultisig,
+
+    #[msg("Signer is not a member of the multisig")]
+    NotAMultisigMember,
+
+    #[msg("Member already approved the proposal")]
+    AlreadyApproved,
+
+    #[msg("Proposal has already been executed")]
+    ProposalExecuted,
+
+    #[msg("Proposal does not have enough approvals")]
+    NotEnoughApprovals,
+
+    #[msg("Signer does not hold the required role for this provider")]
+    RoleUnauthorized,
+
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+
+    #[msg("Provider is listed for sale")]
+    ProviderListed,
+
+    #[msg("Token accounts do not match the payment mint / payer / recipient")]
+    InvalidPaymentAccount,
+
+    #[msg("Discounts should not exceed 10000 basis points / too many holder discounts")]
+    InvalidPricingRules,
+
+    #[msg("Coupon is expired or fully redeemed")]
+    CouponUnavailable,
+
+    #[msg("Coupon code does not match the coupon")]
+    InvalidCouponCode,
+
+    #[msg("Bindies / links do not qualify the owner for the discount")]
+    InvalidPricingAccount,
+
+    #[msg("Minimum custom fee should not exceed the maximum custom fee")]
+    InvalidFeeBounds,
+
+    #[msg("Custom registration fee is outside of the provider's bounds")]
+    CustomFeeOutOfBounds,
+
+    #[msg("Registration fee exceeds the maximum fee set by the owner")]
+    FeeExceedsMaximum,
+
+    #[msg("Revenue split recipients should be unique and their shares should add up to 10000 basis points")]
+    InvalidRevenueSplit,
+
+    #[msg("Vault does not have enough balance to withdraw while staying rent exempt")]
+    InsufficientVaultBalance,
+
+    #[msg("Integrator share exceeds the global maximum / integrator is the owner")]
+    InvalidIntegratorShare,
+
+    #[msg("Refund share should not exceed 10000 basis points / refund window is only supported for native SOL without revenue split or licenses")]
+    InvalidRefundPolicy,
+
+    #[msg("Escrow / provider vault / bindie owner accounts are required during the refund window only")]
+    MissingEscrowAccount,
+
+    #[msg("Refund window has not passed yet")]
+    EscrowLocked,
+
+    #[msg(
+        "Provider does not charge a subscription / subscriptions are only supported for native SOL without licenses"
+    )]
+    InvalidSubscription,
+
+    #[msg("Bindie is still paid for the current period")]
+    SubscriptionNotDue,
+
+    #[msg("Subscription does not have enough balance to pay for the next period")]
+    InsufficientSubscriptionBalance,
+
+    #[msg("Bindie is still paid for / its subscription can still pay for the next period")]
+    SubscriptionNotLapsed,
+
+    #[msg("Bindie subscription has lapsed")]
+    SubscriptionLapsed,
+
+    #[msg("Sub-namespace requires the approval of its parent provider")]
+    SubProviderUnauthorized,
+
+    #[msg("License is invalid, expired or does not match the validator")]
+    InvalidLicense,
+
+    #[msg("Data schema is invalid")]
+    InvalidDataSchema,
+
+    #[msg("Data does not match the provider's data schema")]
+    DataSchemaMismatch,
+
+    #[msg("Revealed data does not match the encrypted data")]
+    InvalidDataReveal,
+
+    #[msg("Prerequisites are invalid")]
+    InvalidPrerequisites,
+
+    #[msg("Owner does not hold a live bindie of every prerequisite provider")]
+    MissingPrerequisite,
+
+    #[msg("Cannot remove an access list with wallet entries")]
+    InvalidAccessList,
+
+    #[msg("Owner's wallet is not allowed to register with this provider")]
+    WalletNotAllowed,
+
+    #[msg("Rate limit requires a window when creations are limited")]
+    InvalidRateLimit,
+
+    #[msg("Too many bindies created within the rate limit window")]
+    RateLimitExceeded,
+
+    #[msg("Provider is paused")]
+    ProviderPaused,
+
+    #[msg("Only the authority or the guardian can pause the provider")]
+    PauseUnauthorized,
+
+    #[msg("Unpause was not requested or its timelock has not passed yet")]
+    UnpauseTimelocked,
+
+    #[msg("Account is not a legacy account of the expected type")]
+    InvalidMigration,
+
+    #[msg("Auction parameters or bidder are invalid")]
+    InvalidAuction,
+
+    #[msg("Auction has already ended")]
+    AuctionEnded,
+
+    #[msg("Auction has not ended yet")]
+    AuctionNotEnded,
+
+    #[msg("Bid is below the reserve price or the minimum increment")]
+    BidTooLow,
+
+    #[msg("Offer amount or offerer is invalid / offer does not match the accepted terms")]
+    InvalidOffer,
 }
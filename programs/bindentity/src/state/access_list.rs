@@ -0,0 +1,58 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+/// An Access List restricts which wallets can register with a Provider.
+/// Wallets are allowed or denied individually through Wallet Entries, or allowed in bulk through a merkle root.
+/// Registration is open to any wallet without a denied entry unless there are allowed wallets.
+#[account]
+pub struct AccessList {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Provider whose registrations are restricted. (32)
+    pub provider: Pubkey,
+
+    /// Merkle root of the allowed wallets, zeroes if none. (32)
+    ///
+    /// Leaves are the SHA 256 hash of the wallet address, pairs are hashed in sorted order.
+    pub allowlist_root: [u8; 32],
+
+    /// Number of allowed wallet entries. (4)
+    pub allowed_count: u32,
+
+    /// Number of denied wallet entries. (4)
+    pub denied_count: u32,
+}
+
+impl AccessList {
+    pub const VERSION: u8 = 1;
+
+    pub fn len() -> usize {
+        8 + 1 + 1 + 32 + 32 + 4 + 4
+    }
+
+    /// True if only allowed wallets can register.
+    pub fn is_restricted(&self) -> bool {
+        self.allowed_count > 0 || self.allowlist_root != [0; 32]
+    }
+
+    pub fn is_in_allowlist(&self, wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        if self.allowlist_root == [0; 32] {
+            return false;
+        }
+
+        let mut node = hashv(&[wallet.as_ref()]).to_bytes();
+
+        for sibling in proof.iter() {
+            node = if node <= *sibling {
+                hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &node]).to_bytes()
+            };
+        }
+
+        node == self.allowlist_root
+    }
+}
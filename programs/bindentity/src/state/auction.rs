@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+/// An Auction sells a Provider listed for sale to the highest bidder, in native SOL.
+/// The highest bid is held by the auction account, and refunded once the bidder is outbid.
+#[account]
+pub struct Auction {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Provider being auctioned. (32)
+    pub provider: Pubkey,
+
+    /// Authority of the provider when the auction started, receives the winning bid. (32)
+    pub seller: Pubkey,
+
+    /// Lowest accepted first bid, in lamports. (8)
+    pub reserve_price: u64,
+
+    /// Lowest amount by which a bid must exceed the highest bid, in lamports. (8)
+    pub min_increment: u64,
+
+    /// Number of seconds the auction is extended to when a bid is placed close to its end. (8)
+    pub extension: i64,
+
+    /// Unix timestamp when the auction ends, unless extended. (8)
+    pub ends_at: i64,
+
+    /// Highest bid, in lamports. (8)
+    pub highest_bid: u64,
+
+    /// Account which placed the highest bid, default if there are no bids yet. (32)
+    pub highest_bidder: Pubkey,
+}
+
+impl Auction {
+    pub const VERSION: u8 = 1;
+
+    pub fn len() -> usize {
+        8 + 1 + 1 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32
+    }
+
+    /// Lowest amount the next bid can be.
+    pub fn min_bid(&self) -> u64 {
+        if self.highest_bidder.key() == Pubkey::default() {
+            self.reserve_price
+        } else {
+            self.highest_bid.saturating_add(self.min_increment)
+        }
+    }
+}
@@ -2,6 +2,9 @@ use anchor_lang::{prelude::*, solana_program::hash::hashv};
 
 /// A Bindie is an account that proves the association of the given data to its owner's wallet account.
 /// A Bindie is considered void if the corresponding Link account is closed.
+/// A subscription Bindie is inactive once `paid_until` has passed, until the subscription is collected again.
+/// If the subscription can no longer pay, anyone can void the Bindie with `void_lapsed_bindie`.
+/// Fields are only appended after `data`, so that the offsets of deployed accounts never move.
 #[account]
 pub struct Bindie {
     /// Bump nonce of the PDA. (1)
@@ -25,17 +28,48 @@ pub struct Bindie {
 
     /// Any data, such as hashed phone number or hashed email. (32 / Varies)
     pub data: String,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Unix timestamp until which the subscription is paid, 0 if the provider charges a one-time fee.
+    /// A subscription bindie is inactive once `paid_until` has passed. (8)
+    pub paid_until: i64,
+
+    /// Parent of the issuing provider, default if the provider is top level.
+    /// Lets the bindies of sub-namespaces be queried as members of the parent. (32)
+    pub parent: Pubkey,
+
+    /// Unused reserved byte space for future additive changes. (32)
+    pub _reserved: [u8; 32],
 }
 
 impl Bindie {
+    pub const VERSION: u8 = 1;
+
     pub fn len(data: String) -> usize {
-        8 + 1 + 32 + 32 + 8 + 1 + (4 + data.len())
+        8 + 1 + 32 + 32 + 8 + 1 + (4 + data.len()) + 1 + 8 + 32 + 32
+    }
+
+    pub fn is_active(&self, now: i64) -> bool {
+        self.paid_until == 0 || now <= self.paid_until
     }
 
     pub fn data_hash(provider_name: &String, data: &String) -> String {
         hashv(&[provider_name.as_bytes(), ":".as_bytes(), data.as_ref()]).to_string()
     }
 
+    /// True if hashing the plaintext `encryption_count - 1` times results in the data passed to `create_bindie`.
+    pub fn is_reveal_of(reveal: &str, data: &str, encryption_count: u8) -> bool {
+        let mut value = reveal.to_string();
+
+        for _ in 1..encryption_count {
+            value = hashv(&[value.as_bytes()]).to_string();
+        }
+
+        value == *data
+    }
+
     pub fn crop(data: &String) -> String {
         if data.len() > 32 {
             data[..32].to_string()
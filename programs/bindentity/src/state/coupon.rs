@@ -0,0 +1,48 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+/// A Coupon is a promo code issued by a Provider, redeemable a limited number of times during registration.
+/// Only the hash of the code is stored, the code itself is revealed when redeeming it in `create_bindie`.
+#[account]
+pub struct Coupon {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Provider which issued the coupon. (32)
+    pub provider: Pubkey,
+
+    /// Discount in basis points of the registration fee, 10000 makes the registration free. (2)
+    pub discount: u16,
+
+    /// Number of times the coupon can be redeemed. (4)
+    pub max_redemptions: u32,
+
+    /// Number of times the coupon has been redeemed. (4)
+    pub redemption_count: u32,
+
+    /// Unix timestamp after which the coupon can no longer be redeemed, 0 if it does not expire. (8)
+    pub expires_at: i64,
+
+    /// SHA 256 hash of the provider and the promo code, also used as seed. (32)
+    pub code_hash: [u8; 32],
+}
+
+impl Coupon {
+    pub const VERSION: u8 = 1;
+
+    pub fn len() -> usize {
+        8 + 1 + 1 + 32 + 2 + 4 + 4 + 8 + 32
+    }
+
+    /// Salted with the provider, so that the same code hashes differently across providers.
+    pub fn hash_code(provider: &Pubkey, code: &str) -> [u8; 32] {
+        hashv(&[provider.as_ref(), code.as_bytes()]).to_bytes()
+    }
+
+    pub fn is_redeemable(&self, now: i64) -> bool {
+        self.redemption_count < self.max_redemptions
+            && (self.expires_at == 0 || now <= self.expires_at)
+    }
+}
@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+
+/// Declares what the plaintext data of a Provider's bindies looks like.
+/// Enforced by `create_bindie` whenever the plaintext is known, and readable by consumers of the namespace.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Default)]
+pub struct DataSchema {
+    /// Kind of data. (1)
+    ///
+    /// * 0 - Any data.
+    /// * 1 - Phone number in E.164 format (eg. `+639171234567`).
+    /// * 2 - Email address, the RFC 5322 `addr-spec` with a dot-atom local part and a domain name.
+    /// * 3 - Ethereum address, `0x` followed by 40 hex digits in any case.
+    /// * 4 - Matches `pattern`.
+    /// * 5 - Exactly `length` bytes long.
+    pub kind: u8,
+
+    /// Length of the data, only for fixed length (5). (1)
+    pub length: u8,
+
+    /// Regex-lite pattern padded with zeroes, only for pattern (4). (32)
+    ///
+    /// The whole data must match. `\d` is a digit, `\a` a letter, `\x` a hex digit, `\w` a letter, digit or `_`
+    /// and `.` any byte, any other byte matches itself or can be escaped with `\`.
+    /// Each of them can be followed by `*` (zero or more), `+` (one or more) or `?` (zero or one).
+    pub pattern: [u8; 32],
+}
+
+impl DataSchema {
+    pub fn len() -> usize {
+        1 + 1 + 32
+    }
+
+    pub fn is_valid(&self) -> bool {
+        match self.kind {
+            0..=3 => true,
+            4 => !self.pattern().is_empty() && Self::is_valid_pattern(self.pattern()),
+            5 => self.length > 0,
+            _ => false,
+        }
+    }
+
+    pub fn matches(&self, data: &String) -> bool {
+        let data = data.as_bytes();
+
+        match self.kind {
+            1 => Self::is_phone(data),
+            2 => Self::is_email(data),
+            3 => Self::is_ethereum_address(data),
+            4 => Self::matches_pattern(self.pattern(), data),
+            5 => data.len() == self.length as usize,
+            _ => true,
+        }
+    }
+
+    fn pattern(&self) -> &[u8] {
+        let end = self
+            .pattern
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(self.pattern.len());
+
+        &self.pattern[..end]
+    }
+
+    fn is_phone(data: &[u8]) -> bool {
+        data.len() >= 3
+            && data.len() <= 16
+            && data[0] == b'+'
+            && (b'1'..=b'9').contains(&data[1])
+            && data[2..].iter().all(u8::is_ascii_digit)
+    }
+
+    fn is_email(data: &[u8]) -> bool {
+        let at = match data.iter().position(|byte| *byte == b'@') {
+            Some(at) => at,
+            None => return false,
+        };
+        let (local, domain) = (&data[..at], &data[at + 1..]);
+
+        let is_atext =
+            |byte: &u8| byte.is_ascii_alphanumeric() || b"!#$%&'*+-/=?^_`{|}~".contains(byte);
+
+        let is_valid_local = local.len() <= 64
+            && local
+                .split(|byte| *byte == b'.')
+                .all(|atom| !atom.is_empty() && atom.iter().all(is_atext));
+
+        let is_valid_domain = domain.len() <= 255
+            && domain.split(|byte| *byte == b'.').count() >= 2
+            && domain.split(|byte| *byte == b'.').all(|label| {
+                !label.is_empty()
+                    && label.len() <= 63
+                    && label[0] != b'-'
+                    && label[label.len() - 1] != b'-'
+                    && label
+                        .iter()
+                        .all(|byte| byte.is_ascii_alphanumeric() || *byte == b'-')
+            });
+
+        is_valid_local && is_valid_domain
+    }
+
+    fn is_ethereum_address(data: &[u8]) -> bool {
+        data.len() == 42 && data.starts_with(b"0x") && data[2..].iter().all(u8::is_ascii_hexdigit)
+    }
+
+    fn is_valid_pattern(pattern: &[u8]) -> bool {
+        let mut i = 0;
+
+        while i < pattern.len() {
+            match pattern[i] {
+                b'*' | b'+' | b'?' => return false,
+                b'\\' if i + 1 == pattern.len() => return false,
+                b'\\' => i += 2,
+                _ => i += 1,
+            }
+
+            if i < pattern.len() && b"*+?".contains(&pattern[i]) {
+                i += 1;
+            }
+        }
+
+        true
+    }
+
+    /// Simulates the pattern as an NFA over the positions of its tokens,
+    /// so matching takes O(pattern * data) steps without any backtracking.
+    fn matches_pattern(pattern: &[u8], data: &[u8]) -> bool {
+        // states[i] is true if the data read so far can continue with the token at pattern[i],
+        // one per byte of the longest pattern plus the end of the pattern
+        let mut states = [false; 33];
+        states[0] = true;
+        Self::skip_optional_tokens(pattern, &mut states);
+
+        for byte in data {
+            let mut next = [false; 33];
+            let mut i = 0;
+
+            while i < pattern.len() {
+                let (class, quantifier, end) = Self::token(pattern, i);
+
+                if states[i] && Self::matches_class(class, *byte) {
+                    match quantifier {
+                        // a repeated token can match again
+                        Some(b'*') => next[i] = true,
+                        Some(b'+') => {
+                            next[i] = true;
+                            next[end] = true;
+                        }
+                        _ => next[end] = true,
+                    }
+                }
+
+                i = end;
+            }
+
+            Self::skip_optional_tokens(pattern, &mut next);
+            states = next;
+        }
+
+        states[pattern.len()]
+    }
+
+    /// Marks the tokens reachable from the marked ones by skipping `*` and `?` tokens.
+    fn skip_optional_tokens(pattern: &[u8], states: &mut [bool; 33]) {
+        let mut i = 0;
+
+        while i < pattern.len() {
+            let (_, quantifier, end) = Self::token(pattern, i);
+
+            if states[i] && matches!(quantifier, Some(b'*') | Some(b'?')) {
+                states[end] = true;
+            }
+
+            i = end;
+        }
+    }
+
+    /// Splits the token at pattern[i] into its class and quantifier, along with the position of the next token.
+    fn token(pattern: &[u8], i: usize) -> (&[u8], Option<u8>, usize) {
+        let class_end = if pattern[i] == b'\\' { i + 2 } else { i + 1 };
+        let class = &pattern[i..class_end];
+
+        match pattern.get(class_end) {
+            Some(quantifier) if b"*+?".contains(quantifier) => {
+                (class, Some(*quantifier), class_end + 1)
+            }
+            _ => (class, None, class_end),
+        }
+    }
+
+    fn matches_class(class: &[u8], byte: u8) -> bool {
+        match class {
+            [b'\\', b'd'] => byte.is_ascii_digit(),
+            [b'\\', b'a'] => byte.is_ascii_alphabetic(),
+            [b'\\', b'x'] => byte.is_ascii_hexdigit(),
+            [b'\\', b'w'] => byte.is_ascii_alphanumeric() || byte == b'_',
+            [b'\\', literal] => byte == *literal,
+            [b'.'] => true,
+            [literal] => byte == *literal,
+            _ => false,
+        }
+    }
+}
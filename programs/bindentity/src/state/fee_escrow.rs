@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+/// A Fee Escrow holds the registration fee of a Bindie during the Provider's refund window.
+/// If the Bindie is voided within the window, part of the fee is refunded to its owner,
+/// otherwise anyone can release the fee to the provider vault once the window has passed.
+#[account]
+pub struct FeeEscrow {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Bindie whose registration fee is escrowed. (32)
+    pub bindie: Pubkey,
+
+    /// Provider which receives the fee once released. (32)
+    pub provider: Pubkey,
+
+    /// Owner of the bindie, receives the refund and the rent of this account. (32)
+    pub owner: Pubkey,
+
+    /// Escrowed registration fee in lamports, on top of the rent. (8)
+    pub amount: u64,
+
+    /// Unix timestamp when the refund window ends. (8)
+    pub release_at: i64,
+
+    /// Share of the fee refunded in basis points, copied from the provider at the time of registration. (2)
+    pub refund_share: u16,
+}
+
+impl FeeEscrow {
+    pub const VERSION: u8 = 1;
+
+    pub fn len() -> usize {
+        8 + 1 + 1 + 32 + 32 + 32 + 8 + 8 + 2
+    }
+
+    /// Closes the escrow, the fee less the refund goes to the provider vault and the rest to the owner.
+    pub fn settle<'info>(
+        escrow: &Account<'info, FeeEscrow>,
+        refund: u64,
+        provider_vault: &AccountInfo<'info>,
+        owner: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let source_account_info = escrow.to_account_info();
+        let fee = escrow.amount - refund;
+
+        **provider_vault.lamports.borrow_mut() =
+            provider_vault.lamports().checked_add(fee).unwrap();
+        **owner.lamports.borrow_mut() = owner
+            .lamports()
+            .checked_add(source_account_info.lamports() - fee)
+            .unwrap();
+        **source_account_info.lamports.borrow_mut() = 0;
+
+        let mut source_data = source_account_info.data.borrow_mut();
+        source_data.fill(0);
+
+        Ok(())
+    }
+}
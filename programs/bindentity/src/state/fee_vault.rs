@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// A Fee Vault holds the fees collected for the protocol (`["vault", global]`) or a Provider (`["vault", provider]`)
+/// until they are withdrawn to the respective treasury.
+/// Native SOL is held as the lamports above rent, SPL tokens in the vault's associated token accounts.
+#[account]
+pub struct FeeVault {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Global or Provider account that this vault collects fees for. (32)
+    pub owner: Pubkey,
+}
+
+impl FeeVault {
+    pub const VERSION: u8 = 1;
+
+    pub fn len() -> usize {
+        8 + 1 + 1 + 32
+    }
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+/// Fields added after `provider_creation_fee` are carved out of the originally reserved 128 bytes,
+/// so that the deployed account keeps its size.
 #[account]
 pub struct Global {
     /// Bump nonce of the PDA. (1)
@@ -8,21 +10,42 @@ pub struct Global {
     /// The authority that is permitted to update this state. (32)
     pub authority: Pubkey,
 
-    /// The wallet that stores the collected fees (used for external services payment). (32)
+    /// The wallet that the collected fees are withdrawn to from the protocol vault (used for external services payment). (32)
     pub treasury: Pubkey,
 
-    /// Amount of fee being collected when a user registers his / her wallet. (8)
+    /// Amount of fee being collected when a user registers his / her wallet, in the payment mint. (8)
     pub service_fee: u64,
 
     /// Fee for registering an identity provider account. (8)
     pub provider_creation_fee: u64,
 
-    /// Unused reserved byte space for future additive changes. (128)
-    pub _reserved: [u8; 128],
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Number of seconds a closed provider name stays reserved for its previous authority. (8)
+    pub provider_cooldown: i64,
+
+    /// Account proposed to take over the authority, default if there is no pending transfer. (32)
+    pub pending_authority: Pubkey,
+
+    /// Mint in which the `service_fee` and `provider_creation_fee` are paid, default for native SOL. (32)
+    pub payment_mint: Pubkey,
+
+    /// Highest share of the service fee in basis points that an integrator can receive for a registration. (2)
+    pub max_integrator_share: u16,
+
+    /// Number of seconds between requesting to unpause a provider and unpausing it. (8)
+    pub unpause_timelock: i64,
+
+    /// Unused reserved byte space for future additive changes, what is left of the original 128 bytes
+    /// once the fields from `version` to `unpause_timelock` (83) were carved out of it. (45)
+    pub _reserved: [u8; 45],
 }
 
 impl Global {
+    pub const VERSION: u8 = 1;
+
     pub fn len() -> usize {
-        8 + 1 + 32 + 32 + 8 + 8 + 128
+        8 + 1 + 32 + 32 + 8 + 8 + 1 + 8 + 32 + 32 + 2 + 8 + 45
     }
 }
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+/// A License lets a licensee issue bindies under the namespace of the Provider until it expires,
+/// with its own treasury, registration fee and validators. The provider takes `parent_share` of each registration fee.
+#[account]
+pub struct License {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Provider which granted the license. (32)
+    pub provider: Pubkey,
+
+    /// Account which manages the license and its validators. (32)
+    pub licensee: Pubkey,
+
+    /// Wallet which receives the licensee's part of the registration fee. (32)
+    pub treasury: Pubkey,
+
+    /// Registration fee charged for bindies issued through the license. (8)
+    pub registration_fee: u64,
+
+    /// Share of the registration fee paid to the provider vault, in basis points. (2)
+    pub parent_share: u16,
+
+    /// Unix timestamp after which the license can no longer issue bindies. (8)
+    pub expires_at: i64,
+
+    /// Number of validators owned by the license. (4)
+    pub validator_count: u32,
+
+    /// Total number of bindies issued through the license. (8)
+    pub issued_count: u64,
+}
+
+impl License {
+    pub const VERSION: u8 = 1;
+
+    pub fn len() -> usize {
+        8 + 1 + 1 + 32 + 32 + 32 + 8 + 2 + 8 + 4 + 8
+    }
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+}
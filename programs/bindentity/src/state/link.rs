@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 /// Link account serves as protection against duplicate bindies.
 /// If a link between the provider and the owner's data is missing, the bindie is considered void.
+/// Fields are only appended after `bindie`, so that the offsets of deployed accounts never move.
 #[account]
 pub struct Link {
     /// Bump nonce of the PDA. (1)
@@ -9,10 +10,18 @@ pub struct Link {
 
     /// Bindie which this link is related to. (32)
     pub bindie: Pubkey,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Unused reserved byte space for future additive changes. (32)
+    pub _reserved: [u8; 32],
 }
 
 impl Link {
+    pub const VERSION: u8 = 1;
+
     pub fn len() -> usize {
-        8 + 1 + 32
+        8 + 1 + 32 + 1 + 32
     }
 }
@@ -1,13 +1,51 @@
+mod access_list;
+mod auction;
 mod bindie;
+mod coupon;
+mod data_schema;
+mod fee_escrow;
+mod fee_vault;
 mod global;
+mod license;
 mod link;
+mod multisig;
+mod multisig_proposal;
+mod offer;
+mod prerequisites;
+mod pricing_rules;
 mod provider;
+mod provider_delegate;
 mod provider_metadata;
+mod rate_limit;
+mod revenue_split;
+mod sub_provider_approval;
+mod subscription;
+mod tombstone;
 mod validator;
+mod wallet_entry;
 
+pub use access_list::*;
+pub use auction::*;
 pub use bindie::*;
+pub use coupon::*;
+pub use data_schema::*;
+pub use fee_escrow::*;
+pub use fee_vault::*;
 pub use global::*;
+pub use license::*;
 pub use link::*;
+pub use multisig::*;
+pub use multisig_proposal::*;
+pub use offer::*;
+pub use prerequisites::*;
+pub use pricing_rules::*;
 pub use provider::*;
+pub use provider_delegate::*;
 pub use provider_metadata::*;
+pub use rate_limit::*;
+pub use revenue_split::*;
+pub use sub_provider_approval::*;
+pub use subscription::*;
+pub use tombstone::*;
 pub use validator::*;
+pub use wallet_entry::*;
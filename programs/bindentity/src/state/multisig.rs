@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+/// A Multisig is an M-of-N authority that can manage providers without an external multisig program.
+/// Its signer PDA (`["multisig_signer", multisig]`) is the key to be assigned as the Provider's authority,
+/// and it signs provider-admin instructions once a proposal has enough approvals.
+#[account]
+pub struct Multisig {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Bump nonce of the signer PDA. (1)
+    pub signer_bump: u8,
+
+    /// Key used as seed for this multisig. (32)
+    pub create_key: Pubkey,
+
+    /// Number of approvals required before a proposal can be executed. (1)
+    pub threshold: u8,
+
+    /// Number of proposals created, used as seed for the next proposal. (4)
+    pub proposal_count: u32,
+
+    /// Accounts permitted to create and approve proposals. (Varies)
+    pub members: Vec<Pubkey>,
+}
+
+impl Multisig {
+    pub const VERSION: u8 = 1;
+
+    pub fn len(members: &[Pubkey]) -> usize {
+        8 + 1 + 1 + 1 + 32 + 1 + 4 + (4 + members.len() * 32)
+    }
+
+    pub fn is_valid(members: &[Pubkey], threshold: u8) -> bool {
+        let has_duplicates = members
+            .iter()
+            .enumerate()
+            .any(|(i, member)| members[..i].contains(member));
+
+        !has_duplicates && threshold > 0 && threshold as usize <= members.len()
+    }
+}
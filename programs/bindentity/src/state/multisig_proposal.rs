@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposalAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// A Multisig Proposal holds a Bindentity instruction to be signed by the Multisig's signer PDA.
+#[account]
+pub struct MultisigProposal {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Multisig which this proposal belongs to. (32)
+    pub multisig: Pubkey,
+
+    /// Member who created this proposal. (32)
+    pub proposer: Pubkey,
+
+    /// Index of the proposal under the multisig. (4)
+    pub index: u32,
+
+    /// True once the instruction has been executed. (1)
+    pub executed: bool,
+
+    /// Members who approved the proposal. (Varies)
+    pub approvals: Vec<Pubkey>,
+
+    /// Accounts of the proposed instruction. (Varies)
+    pub accounts: Vec<ProposalAccount>,
+
+    /// Data of the proposed instruction. (Varies)
+    pub data: Vec<u8>,
+}
+
+impl MultisigProposal {
+    pub const VERSION: u8 = 1;
+
+    pub fn len(member_count: usize, accounts: &[ProposalAccount], data: &[u8]) -> usize {
+        8 + 1
+            + 1
+            + 32
+            + 32
+            + 4
+            + 1
+            + (4 + member_count * 32)
+            + (4 + accounts.len() * (32 + 1 + 1))
+            + (4 + data.len())
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+/// An Offer to buy a Provider, whether it is listed for sale or not, in native SOL.
+/// The offered amount is held by the offer account until it is accepted or cancelled.
+#[account]
+pub struct Offer {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Provider the offer is made for. (32)
+    pub provider: Pubkey,
+
+    /// Account which made the offer, becomes the authority once accepted. (32)
+    pub offerer: Pubkey,
+
+    /// Offered amount, in lamports. (8)
+    pub amount: u64,
+
+    /// Treasury of the provider once the offer is accepted. (32)
+    pub provider_treasury: Pubkey,
+
+    /// Registration fee of the provider once the offer is accepted. (8)
+    pub registration_fee: u64,
+}
+
+impl Offer {
+    pub const VERSION: u8 = 1;
+
+    pub fn len() -> usize {
+        8 + 1 + 1 + 32 + 32 + 8 + 32 + 8
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// Prerequisites are the providers whose live bindies the owner must already hold
+/// before `create_bindie` of the Provider succeeds (eg. `bank_kyc` requires `phone`).
+#[account]
+pub struct Prerequisites {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Provider which requires the prerequisites. (32)
+    pub provider: Pubkey,
+
+    /// Required providers in the order their bindies are expected as remaining accounts. (Varies)
+    pub providers: Vec<Pubkey>,
+}
+
+impl Prerequisites {
+    pub const VERSION: u8 = 1;
+    pub const MAX_PROVIDERS: usize = 4;
+
+    pub fn len(providers: &[Pubkey]) -> usize {
+        8 + 1 + 1 + 32 + (4 + providers.len() * 32)
+    }
+
+    pub fn is_valid(provider: &Pubkey, providers: &[Pubkey]) -> bool {
+        let has_duplicates = providers
+            .iter()
+            .enumerate()
+            .any(|(i, required)| providers[..i].contains(required));
+
+        !has_duplicates
+            && !providers.is_empty()
+            && providers.len() <= Self::MAX_PROVIDERS
+            && !providers.contains(provider)
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+/// Discount granted to owners who already hold a live bindie from another provider.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct HolderDiscount {
+    /// Provider whose bindie qualifies the owner for the discount. (32)
+    pub provider: Pubkey,
+
+    /// Discount in basis points of the registration fee, 10000 makes the registration free. (2)
+    pub discount: u16,
+}
+
+/// Pricing Rules are evaluated by `create_bindie` on top of the registration fee.
+/// Only the largest applicable discount is granted, discounts do not stack.
+#[account]
+pub struct PricingRules {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Provider which these rules apply to. (32)
+    pub provider: Pubkey,
+
+    /// Discount in basis points when the owner re-registers data they previously held, 10000 for free renewals. (2)
+    pub renewal_discount: u16,
+
+    /// Discounts for holders of other providers' bindies. (Varies)
+    pub holder_discounts: Vec<HolderDiscount>,
+}
+
+impl PricingRules {
+    pub const VERSION: u8 = 1;
+    pub const MAX_HOLDER_DISCOUNTS: usize = 8;
+
+    pub fn len(holder_discounts: &[HolderDiscount]) -> usize {
+        8 + 1 + 1 + 32 + 2 + (4 + holder_discounts.len() * (32 + 2))
+    }
+
+    pub fn is_valid(renewal_discount: u16, holder_discounts: &[HolderDiscount]) -> bool {
+        renewal_discount <= 10000
+            && holder_discounts.len() <= Self::MAX_HOLDER_DISCOUNTS
+            && holder_discounts
+                .iter()
+                .all(|holder_discount| holder_discount.discount <= 10000)
+    }
+
+    pub fn holder_discount(&self, provider: &Pubkey) -> u16 {
+        self.holder_discounts
+            .iter()
+            .filter(|holder_discount| holder_discount.provider == *provider)
+            .map(|holder_discount| holder_discount.discount)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Applies a discount in basis points to the given fee.
+    pub fn discounted(fee: u64, discount: u16) -> u64 {
+        (fee as u128 * (10000 - discount.min(10000)) as u128 / 10000) as u64
+    }
+}
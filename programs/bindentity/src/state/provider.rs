@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{DataSchema, ProviderDelegate, RateLimit};
+
 /// A Provider is the owner of a specific ID name.
 /// A Provider also manages several Validator accounts.
+/// Fields are only appended after `name`, so that the offsets of deployed accounts never move.
 #[account]
 pub struct Provider {
     /// Bump nonce of the PDA. (1)
@@ -15,34 +18,206 @@ pub struct Provider {
     /// * 8 - Listed for sale: `validator_count` must be 0 and the provider should be unpublished.
     /// * 16 - Has validator: for filter purposes, true if `validator_count` is greater than 0.
     /// * 32 - Has metadata
+    /// * 64 - Has revenue split: registration fees are distributed across the split's recipients instead of `treasury`.
+    /// * 128 - Has prerequisites: owners must hold live bindies of the required providers to register.
+    /// * 256 - Has access list: owners are checked against the allowed and denied wallets.
+    /// * 512 - Paused: halts registrations, voids and validator changes during an incident.
+    /// * 1024 - In auction: listed for sale through an auction instead of `selling_price`.
+    /// * 2048 - Has pricing rules
+    /// * 4096 - Migrated: created before `live_count` was tracked, so it can never be closed.
     pub flags: u16,
 
     /// Account that manages this identity provider. (32)
     pub authority: Pubkey,
 
-    /// Account that holds the collected registration fees. (32)
+    /// Account that the collected registration fees are withdrawn to from the provider vault. (32)
     pub treasury: Pubkey,
 
     /// Default fee collected to the user when availing the offered service.
-    /// If the validator is permitted to customize the fee, this field will be ignored. (8)
+    /// If the validator is permitted to customize the fee, this field will be ignored
+    /// in favor of the custom fee within `min_custom_fee` and `max_custom_fee`. (8)
     pub registration_fee: u64,
 
     /// Number of validators under this provider. (4)
     pub validator_count: u32,
 
-    /// The selling price when put up for sale, in lamports. (8)
+    /// The selling price when put up for sale, in the payment mint. (8)
     /// Note that the `validator_count` must be 0 in order for this provider to be listed.
     pub selling_price: u64,
 
-    /// Unused reserved byte space for future additive changes. (32)
-    pub _reserved: [u8; 32],
+    /// The `provider` bindie that backs the verified flag (4), default if not verified. (32)
+    pub verified_bindie: Pubkey,
 
     /// The unique name of the provider (eg. email, phone, metamask, ph_national_id). (Varies)
     pub name: String,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Account proposed to take over the authority, default if there is no pending transfer. (32)
+    pub pending_authority: Pubkey,
+
+    /// Total number of bindies issued under this provider. (8)
+    pub issued_count: u64,
+
+    /// Number of bindies under this provider that are not yet void. (8)
+    pub live_count: u64,
+
+    /// Total number of bindies under this provider that have been voided. (8)
+    pub voided_count: u64,
+
+    /// Mint in which the `registration_fee` and `selling_price` are paid, default for native SOL. (32)
+    pub payment_mint: Pubkey,
+
+    /// Total registration fees collected by this provider, in the payment mint. (8)
+    pub collected_fees: u64,
+
+    /// Lowest registration fee a validator is permitted to charge when customizing the fee. (8)
+    pub min_custom_fee: u64,
+
+    /// Highest registration fee a validator is permitted to charge when customizing the fee. (8)
+    pub max_custom_fee: u64,
+
+    /// Number of seconds after registration during which the registration fee is escrowed
+    /// and partially refunded if the bindie is voided, 0 if disabled.
+    /// Only supported for native SOL, without a revenue split (64) or licenses. (8)
+    pub refund_window: i64,
+
+    /// Share of the registration fee refunded in basis points when voided within the refund window. (2)
+    pub refund_share: u16,
+
+    /// Number of seconds a registration pays for, the `registration_fee` is then collected every period
+    /// through the bindie's subscription, 0 for a one-time fee. Only supported for native SOL. (8)
+    pub subscription_period: i64,
+
+    /// Parent provider if this is a sub-namespace (`parent/child`), default for a top level provider. (32)
+    pub parent: Pubkey,
+
+    /// Format of the plaintext data, validated by `create_bindie` when the plaintext is known. (34)
+    pub data_schema: DataSchema,
+
+    /// Bounds the bindies created under this provider across all validators, unlimited by default. (24)
+    pub rate_limit: RateLimit,
+
+    /// Account permitted to pause the provider besides the authority, default if none. (32)
+    pub guardian: Pubkey,
+
+    /// Unix timestamp from which the paused provider can be unpaused, 0 if no unpause was requested. (8)
+    pub unpause_at: i64,
+
+    /// Number of delegates of this provider. (4)
+    pub delegate_count: u32,
+
+    /// Number of licenses granted by this provider which have not been revoked. (4)
+    pub license_count: u32,
+
+    /// Number of coupons of this provider. (4)
+    pub coupon_count: u32,
+
+    /// Number of sub-namespace approvals which have not been consumed nor revoked. (4)
+    pub approval_count: u32,
+
+    /// Incremented whenever the authority changes, delegates granted under a previous authority lose their roles. (4)
+    pub authority_epoch: u32,
+
+    /// Number of sub-namespaces (`name/child`) created under this provider which have not been closed. (4)
+    pub child_count: u32,
+
+    /// Unused reserved byte space for future additive changes, what is left of the 64 bytes
+    /// reserved after `name` once the counters and `authority_epoch` were carved out of it. (40)
+    pub _reserved: [u8; 40],
 }
 
 impl Provider {
+    pub const VERSION: u8 = 1;
+
     pub fn len(name: &String) -> usize {
-        8 + 1 + 2 + 32 + 32 + 8 + 4 + 8 + 32 + (4 + name.len())
+        8 + 1
+            + 2
+            + 32
+            + 32
+            + 8
+            + 4
+            + 8
+            + 32
+            + (4 + name.len())
+            + 1
+            + 32
+            + 8
+            + 8
+            + 8
+            + 32
+            + 8
+            + 8
+            + 8
+            + 8
+            + 2
+            + 8
+            + 32
+            + DataSchema::len()
+            + RateLimit::len()
+            + 32
+            + 8
+            + 4
+            + 4
+            + 4
+            + 4
+            + 4
+            + 4
+            + 40
+    }
+
+    /// True if the given name is a direct sub-namespace of this provider (eg. `ph_id/sss` of `ph_id`).
+    pub fn is_parent_of(&self, name: &str) -> bool {
+        match name.rfind('/') {
+            Some(index) => name[..index] == self.name && index + 1 < name.len(),
+            None => false,
+        }
+    }
+
+    /// Resets the provider as if newly created, and assigns the new authority.
+    /// A paused provider stays paused (512), the new authority has to request the unpause again.
+    /// The flags of the accounts which outlive the sale are kept: metadata (32), revenue split (64),
+    /// prerequisites (128), access list (256) and pricing rules (2048), along with the migrated flag (4096).
+    pub fn reset_ownership(
+        &mut self,
+        authority: &Pubkey,
+        treasury: &Pubkey,
+        registration_fee: u64,
+    ) {
+        self.authority = authority.key();
+        self.authority_epoch = self.authority_epoch.wrapping_add(1);
+        self.flags = 1 | (self.flags & (32 | 64 | 128 | 256 | 512 | 2048 | 4096));
+        self.treasury = treasury.key();
+        self.registration_fee = registration_fee;
+        self.validator_count = 0;
+        self.selling_price = 0;
+        self.payment_mint = Pubkey::default();
+        self.verified_bindie = Pubkey::default();
+        self.pending_authority = Pubkey::default();
+        self.min_custom_fee = 0;
+        self.max_custom_fee = u64::MAX;
+        self.refund_window = 0;
+        self.refund_share = 0;
+        self.subscription_period = 0;
+        self.rate_limit = RateLimit::default();
+        self.guardian = Pubkey::default();
+        self.unpause_at = 0;
+    }
+
+    /// The authority holds every role, a delegate only holds the roles granted to it by the current authority.
+    pub fn has_role(&self, signer: &Pubkey, delegate: Option<&ProviderDelegate>, role: u8) -> bool {
+        if self.authority.key() == signer.key() {
+            return true;
+        }
+
+        match delegate {
+            Some(delegate) => {
+                delegate.delegate.key() == signer.key()
+                    && delegate.roles & role == role
+                    && delegate.authority_epoch == self.authority_epoch
+            }
+            None => false,
+        }
     }
 }
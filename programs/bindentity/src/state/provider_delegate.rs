@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// A Provider Delegate grants a subset of the Provider's administrative roles to another account.
+/// The Provider's authority is the owner and implicitly holds every role.
+#[account]
+pub struct ProviderDelegate {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Binary flags which describes the roles granted to the delegate. (1)
+    ///
+    /// * 1 - Fee manager: permitted to update `treasury`, `registration_fee` and `payment_mint`,
+    ///   and to manage the coupons, pricing rules and revenue split.
+    /// * 2 - Validator manager: permitted to create, update and close validators,
+    ///   and to grant and revoke the licenses which let others run validators.
+    /// * 4 - Metadata manager: permitted to manage the metadata and publish the provider,
+    ///   and to manage who can register: the access list, prerequisites and data schema.
+    /// * 8 - Sales manager: permitted to update `for_sale` and `selling_price`, start auctions,
+    ///   and to approve and revoke sub-providers.
+    pub roles: u8,
+
+    /// Provider which granted the roles. (32)
+    pub provider: Pubkey,
+
+    /// Account that is permitted to act on the granted roles. (32)
+    pub delegate: Pubkey,
+
+    /// `authority_epoch` of the provider when the roles were granted, the roles lapse once the authority changes. (4)
+    pub authority_epoch: u32,
+}
+
+impl ProviderDelegate {
+    pub const VERSION: u8 = 1;
+
+    pub fn len() -> usize {
+        8 + 1 + 1 + 1 + 32 + 32 + 4
+    }
+}
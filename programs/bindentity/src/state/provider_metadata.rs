@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-/// A Provider Metadata stores the URI of the off-chain json file for additional information about the Provider
+/// A Provider Metadata stores the URI of the off-chain json file for additional information about the Provider,
+/// together with the on-chain copy of the fields that wallets and other programs are most likely to read.
+/// Fields are only appended after `uri`, so that the offsets of deployed accounts never move.
 #[account]
 pub struct ProviderMetadata {
     /// Bump nonce of the PDA. (1)
@@ -15,10 +17,33 @@ pub struct ProviderMetadata {
     /// * `website: string` (url of the marketing website)
     /// * `registration_url: string` (important! url to redirect the user when availing the provider's service)
     pub uri: String,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// SHA 256 hash of the off-chain JSON content, so clients can check that it has not been tampered with. (1 + 32)
+    pub content_hash: Option<[u8; 32]>,
+
+    /// On-chain copy of the user friendly name of the id, empty if not set. (Varies)
+    pub display_name: String,
+
+    /// On-chain copy of the url to redirect the user when availing the provider's service, empty if not set. (Varies)
+    pub registration_url: String,
+
+    /// On-chain copy of the tags for filter purposes. (Varies)
+    pub tags: Vec<String>,
 }
 
 impl ProviderMetadata {
-    pub fn len(uri: &String) -> usize {
-        8 + 1 + (4 + uri.len())
+    pub const VERSION: u8 = 1;
+
+    pub fn len(uri: &str, display_name: &str, registration_url: &str, tags: &[String]) -> usize {
+        8 + 1
+            + (4 + uri.len())
+            + 1
+            + (1 + 32)
+            + (4 + display_name.len())
+            + (4 + registration_url.len())
+            + (4 + tags.iter().map(|tag| 4 + tag.len()).sum::<usize>())
     }
 }
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+/// Bounds the number of bindies created within a window of slots, the counter rolls over once the window passes.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Default)]
+pub struct RateLimit {
+    /// Maximum number of creations per window, 0 if unlimited. (4)
+    pub max_creations: u32,
+
+    /// Length of the window in slots. (8)
+    pub window_slots: u64,
+
+    /// Slot at which the current window started. (8)
+    pub window_start: u64,
+
+    /// Number of creations within the current window. (4)
+    pub window_count: u32,
+}
+
+impl RateLimit {
+    pub fn len() -> usize {
+        4 + 8 + 8 + 4
+    }
+
+    pub fn is_valid(max_creations: u32, window_slots: u64) -> bool {
+        max_creations == 0 || window_slots > 0
+    }
+
+    /// Counts a creation at the given slot, false if the limit of the current window is reached.
+    pub fn record(&mut self, slot: u64) -> bool {
+        if self.max_creations == 0 {
+            return true;
+        }
+
+        if slot >= self.window_start.saturating_add(self.window_slots) {
+            self.window_start = slot;
+            self.window_count = 0;
+        }
+
+        if self.window_count >= self.max_creations {
+            return false;
+        }
+
+        self.window_count += 1;
+        true
+    }
+}
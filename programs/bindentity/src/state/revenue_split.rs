@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+/// Recipient of a share of the registration fees.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct SplitRecipient {
+    /// Account that receives the share. (32)
+    pub recipient: Pubkey,
+
+    /// Share in basis points of the registration fee. (2)
+    pub share: u16,
+}
+
+/// A Revenue Split distributes the registration fees of a Provider across several treasuries,
+/// replacing the Provider's `treasury` as the destination of the fees while it exists.
+#[account]
+pub struct RevenueSplit {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Provider whose registration fees are split. (32)
+    pub provider: Pubkey,
+
+    /// Recipients in the order they are expected as remaining accounts, shares should add up to 10000. (Varies)
+    pub recipients: Vec<SplitRecipient>,
+}
+
+impl RevenueSplit {
+    pub const VERSION: u8 = 1;
+    pub const MAX_RECIPIENTS: usize = 8;
+
+    pub fn len(recipients: &[SplitRecipient]) -> usize {
+        8 + 1 + 1 + 32 + (4 + recipients.len() * (32 + 2))
+    }
+
+    pub fn is_valid(recipients: &[SplitRecipient]) -> bool {
+        let has_duplicates = recipients.iter().enumerate().any(|(i, split)| {
+            recipients[..i]
+                .iter()
+                .any(|other| other.recipient == split.recipient)
+        });
+
+        !has_duplicates
+            && !recipients.is_empty()
+            && recipients.len() <= Self::MAX_RECIPIENTS
+            && recipients
+                .iter()
+                .map(|split| split.share as u32)
+                .sum::<u32>()
+                == 10000
+    }
+
+    /// Amount of the fee owed to each recipient, the rounding remainder goes to the last recipient.
+    pub fn shares(&self, fee: u64) -> Vec<u64> {
+        let mut remaining = fee;
+
+        self.recipients
+            .iter()
+            .enumerate()
+            .map(|(i, split)| {
+                let amount = if i == self.recipients.len() - 1 {
+                    remaining
+                } else {
+                    (fee as u128 * split.share as u128 / 10000) as u64
+                };
+
+                remaining -= amount;
+                amount
+            })
+            .collect()
+    }
+}
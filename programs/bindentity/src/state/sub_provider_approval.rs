@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// A Sub Provider Approval lets the given owner create a sub-namespace (`parent/child`) under the parent Provider.
+/// It is consumed by `create_provider`, which returns the rent to the payer of the approval.
+#[account]
+pub struct SubProviderApproval {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Parent provider which approved the sub-namespace. (32)
+    pub parent: Pubkey,
+
+    /// Account permitted to create the sub-namespace. (32)
+    pub owner: Pubkey,
+
+    /// Full name of the sub-namespace, including the parent's name. (Varies)
+    pub name: String,
+
+    /// Account which paid the rent, receives it back once the approval is consumed. (32)
+    pub payer: Pubkey,
+}
+
+impl SubProviderApproval {
+    pub const VERSION: u8 = 1;
+
+    pub fn len(name: &str) -> usize {
+        8 + 1 + 1 + 32 + 32 + (4 + name.len()) + 32
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// A Subscription is the owner's pre-funded escrow which pays for the recurring fee of a Bindie.
+/// The owner tops it up by transferring lamports to it, anyone can collect the fee once the Bindie is due.
+#[account]
+pub struct Subscription {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Bindie which the subscription pays for. (32)
+    pub bindie: Pubkey,
+
+    /// Owner of the bindie, receives the remaining lamports when cancelled. (32)
+    pub owner: Pubkey,
+
+    /// Fee collected every period in lamports, copied from the provider's `registration_fee` when subscribing. (8)
+    pub fee: u64,
+
+    /// Number of seconds each fee pays for, copied from the provider's `subscription_period` when subscribing,
+    /// so that later changes to the provider do not affect the bindie. (8)
+    pub period: i64,
+}
+
+impl Subscription {
+    pub const VERSION: u8 = 1;
+
+    pub fn len() -> usize {
+        8 + 1 + 1 + 32 + 32 + 8 + 8
+    }
+}
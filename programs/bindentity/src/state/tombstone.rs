@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// A Tombstone is left behind when a Provider is closed.
+/// It keeps the name reserved for its previous authority until the global cooldown has passed.
+#[account]
+pub struct Tombstone {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Authority of the closed provider. (32)
+    pub authority: Pubkey,
+
+    /// Unix timestamp when the provider was closed. (8)
+    pub closed_at: i64,
+
+    /// Cooldown in seconds at the time of closing, copied from the global config. (8)
+    pub cooldown: i64,
+}
+
+impl Tombstone {
+    pub const VERSION: u8 = 1;
+
+    pub fn len() -> usize {
+        8 + 1 + 1 + 32 + 8 + 8
+    }
+}
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::state::RateLimit;
+
 /// A Validator is a delegate of the Provider which holds the authorized Signer.
 /// A Signer is authorized to verify and approve any identity-related transaction.
 /// It can be a person, a multisig, or a keypair securely stored in the Provider's backend server.
+/// Fields are only appended after `signer`, deployed validators are grown by `migrate_validator`.
 #[account]
 pub struct Validator {
     /// Bump nonce of the PDA. (1)
@@ -20,10 +23,30 @@ pub struct Validator {
 
     /// Account that is permitted to verify and approve transactions. (32)
     pub signer: Pubkey,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Total number of bindies issued through this validator. (8)
+    pub issued_count: u64,
+
+    /// Total number of bindies voided through this validator. (8)
+    pub voided_count: u64,
+
+    /// License which owns this validator, or the default pubkey if owned by the provider itself. (32)
+    pub license: Pubkey,
+
+    /// Bounds the bindies created through this validator, unlimited by default. (24)
+    pub rate_limit: RateLimit,
+
+    /// Unused reserved byte space for future additive changes. (32)
+    pub _reserved: [u8; 32],
 }
 
 impl Validator {
+    pub const VERSION: u8 = 1;
+
     pub fn len() -> usize {
-        8 + 1 + 1 + 32 + 32
+        8 + 1 + 1 + 32 + 32 + 1 + 8 + 8 + 32 + RateLimit::len() + 32
     }
 }
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+/// A Wallet Entry allows or denies a single wallet in the Access List of a Provider.
+#[account]
+pub struct WalletEntry {
+    /// Bump nonce of the PDA. (1)
+    pub bump: u8,
+
+    /// Version of the account layout. (1)
+    pub version: u8,
+
+    /// Provider whose access list contains the entry. (32)
+    pub provider: Pubkey,
+
+    /// Wallet which is allowed or denied. (32)
+    pub wallet: Pubkey,
+
+    /// True if the wallet is allowed, false if denied. (1)
+    pub allowed: bool,
+}
+
+impl WalletEntry {
+    pub const VERSION: u8 = 1;
+
+    pub fn len() -> usize {
+        8 + 1 + 1 + 32 + 32 + 1
+    }
+}
@@ -0,0 +1,179 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{self, Mint, TokenAccount},
+};
+
+use crate::{state::FeeVault, CustomError};
+
+/// Transfers a fee from the payer to the recipient in the given payment mint.
+///
+/// * Native SOL (`Pubkey::default()`) is transferred through the system program.
+/// * Any other mint is transferred from the payer's token account to the recipient's associated token account,
+///   `token_accounts` being the `(source, destination)` pair and `token_program` the SPL Token program.
+pub fn transfer_fee<'info>(
+    payment_mint: &Pubkey,
+    amount: u64,
+    payer: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    token_accounts: Option<(&Account<'info, TokenAccount>, &Account<'info, TokenAccount>)>,
+    system_program: &AccountInfo<'info>,
+    token_program: Option<&AccountInfo<'info>>,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if payment_mint.key() == Pubkey::default() {
+        let cpi_ctx = CpiContext::new(
+            system_program.clone(),
+            system_program::Transfer {
+                from: payer.clone(),
+                to: recipient.clone(),
+            },
+        );
+
+        return system_program::transfer(cpi_ctx, amount);
+    }
+
+    let (source, destination, token_program) = match (token_accounts, token_program) {
+        (Some((source, destination)), Some(token_program)) => (source, destination, token_program),
+        _ => return Err(error!(CustomError::InvalidPaymentAccount)),
+    };
+
+    if source.mint.key() != payment_mint.key()
+        || source.owner.key() != payer.key()
+        || destination.key() != get_associated_token_address(&recipient.key(), payment_mint)
+    {
+        return Err(error!(CustomError::InvalidPaymentAccount));
+    }
+
+    let cpi_ctx = CpiContext::new(
+        token_program.clone(),
+        token::Transfer {
+            from: source.to_account_info(),
+            to: destination.to_account_info(),
+            authority: payer.clone(),
+        },
+    );
+
+    token::transfer(cpi_ctx, amount)
+}
+
+/// True if the payment mint is native SOL (`Pubkey::default()`), or the given account is its mint.
+pub fn is_valid_payment_mint(payment_mint: &Pubkey, mint: Option<&Account<Mint>>) -> bool {
+    *payment_mint == Pubkey::default() || mint.is_some_and(|mint| mint.key() == *payment_mint)
+}
+
+/// Withdraws fees from a vault to the treasury.
+///
+/// * Native SOL is withdrawn from the lamports of the vault, which must stay rent exempt.
+/// * SPL token is withdrawn from the vault's associated token account to the treasury's,
+///   `token_accounts` being the `(source, destination)` pair and `token_program` the SPL Token program.
+pub fn withdraw_fee<'info>(
+    vault: &Account<'info, FeeVault>,
+    amount: u64,
+    treasury: &AccountInfo<'info>,
+    token_accounts: Option<(&Account<'info, TokenAccount>, &Account<'info, TokenAccount>)>,
+    token_program: Option<&AccountInfo<'info>>,
+) -> Result<()> {
+    let (source, destination) = match token_accounts {
+        Some(token_accounts) => token_accounts,
+        None => {
+            let source_account_info = vault.to_account_info();
+            let rent_exempt = Rent::get()?.minimum_balance(source_account_info.data_len());
+
+            if source_account_info.lamports().saturating_sub(amount) < rent_exempt {
+                return Err(error!(CustomError::InsufficientVaultBalance));
+            }
+
+            **source_account_info.lamports.borrow_mut() -= amount;
+            **treasury.lamports.borrow_mut() = treasury.lamports().checked_add(amount).unwrap();
+
+            return Ok(());
+        }
+    };
+
+    let token_program = match token_program {
+        Some(token_program) => token_program,
+        None => return Err(error!(CustomError::InvalidPaymentAccount)),
+    };
+
+    if source.key() != get_associated_token_address(&vault.key(), &source.mint)
+        || destination.key() != get_associated_token_address(&treasury.key(), &source.mint)
+    {
+        return Err(error!(CustomError::InvalidPaymentAccount));
+    }
+
+    let signer_seeds: &[&[&[u8]]] = &[&["vault".as_bytes(), vault.owner.as_ref(), &[vault.bump]]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.clone(),
+        token::Transfer {
+            from: source.to_account_info(),
+            to: destination.to_account_info(),
+            authority: vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    token::transfer(cpi_ctx, amount)
+}
+
+/// Upgrades an account created before its type had a version (0) to the current layout.
+///
+/// Fields are only ever appended or carved out of reserved space, so the legacy data is kept in place:
+/// the account is grown to `new_len` with zeroed bytes, the payer tops up the rent of the larger account,
+/// and `migrate` sets the version and the fields whose default is not zero.
+pub fn migrate_legacy_account<'info, T>(
+    account: &AccountInfo<'info>,
+    legacy_len: usize,
+    new_len: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    migrate: impl FnOnce(&mut T) -> Result<()>,
+) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Discriminator,
+{
+    if account.owner != &crate::ID
+        || account.data_len() != legacy_len
+        || account.try_borrow_data()?[..8] != T::DISCRIMINATOR
+    {
+        return Err(error!(CustomError::InvalidMigration));
+    }
+
+    let rent = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+
+    if rent > 0 {
+        let cpi_ctx = CpiContext::new(
+            system_program.clone(),
+            system_program::Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+        );
+
+        system_program::transfer(cpi_ctx, rent)?;
+    }
+
+    account.realloc(new_len, true)?;
+
+    let mut state = T::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    migrate(&mut state)?;
+    state.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+/// Length of a legacy account whose last field is a string at the given offset.
+pub fn legacy_len_with_string(account: &AccountInfo, offset: usize) -> Result<usize> {
+    let data = account.try_borrow_data()?;
+
+    match data.get(offset..offset + 4) {
+        Some(len) => Ok(offset + 4 + u32::from_le_bytes(len.try_into().unwrap()) as usize),
+        None => Err(error!(CustomError::InvalidMigration)),
+    }
+}
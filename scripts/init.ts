@@ -1,4 +1,6 @@
 import { AnchorProvider, BN, Program } from '@coral-xyz/anchor'
+import { createHash } from 'crypto'
+import { readFileSync } from 'fs'
 // import { findProgramAddressSync } from '@coral-xyz/anchor/dist/cjs/utils/pubkey'
 import {
   Keypair,
@@ -44,6 +46,12 @@ const [globalPda] = PublicKey.findProgramAddressSync(
   programId,
 )
 
+const findVaultPda = (owner: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from('vault'), owner.toBytes()],
+    programId,
+  )[0]
+
 const officialProviders = [
   'phone',
   'email',
@@ -65,6 +73,7 @@ const init = async () => {
     try {
       const accounts = {
         global: globalPda,
+        vault: findVaultPda(globalPda),
         authority: authority.publicKey,
         program: programId,
         programData: programDataPda,
@@ -107,6 +116,14 @@ const init = async () => {
         program.programId,
       )
 
+      const [tombstonePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('tombstone', 'utf-8'),
+          Buffer.from(bindentityName, 'utf-8'),
+        ],
+        program.programId,
+      )
+
       const providerIx = await program.methods
         .createProvider({
           name: bindentityName,
@@ -114,10 +131,18 @@ const init = async () => {
           registrationFee,
         })
         .accounts({
-          treasury: treasury.publicKey,
+          vault: findVaultPda(globalPda),
           global: globalPda,
           owner: authority.publicKey,
           provider: providerPda,
+          providerVault: findVaultPda(providerPda),
+          tombstone: tombstonePda,
+          parent: null,
+          approval: null,
+          approvalPayer: null,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          tokenProgram: null,
           systemProgram: SystemProgram.programId,
         })
         .instruction()
@@ -130,12 +155,25 @@ const init = async () => {
           program.programId,
         )
 
+        const metadataJSON = readFileSync(
+          `./metadata/${bindentityName}.json`,
+          'utf-8',
+        )
+        const metadata = JSON.parse(metadataJSON)
+
         const metadataIx = await program.methods
           .createProviderMetadata({
             uri: `https://shdw-drive.genesysgo.net/EQUAMGwdZNwhuZxXVFeVmxVYd3ZWMhL1TYFoM1WScLgQ/${bindentityName}.json`,
+            contentHash: [
+              ...createHash('sha256').update(metadataJSON).digest(),
+            ],
+            displayName: metadata.name,
+            registrationUrl: metadata.registration_url,
+            tags: metadata.tags,
           })
           .accounts({
             authority: authority.publicKey,
+            delegate: null,
             provider: providerPda,
             providerMetadata: providerMetadataPda,
             systemProgram: SystemProgram.programId,
@@ -147,15 +185,26 @@ const init = async () => {
         const publishIx = await program.methods
           .updateProvider({
             published: true,
-            authority: null,
             forSale: null,
             registrationFee: null,
             sellingPrice: null,
             treasury: null,
+            paymentMint: null,
+            minCustomFee: null,
+            maxCustomFee: null,
+            refundWindow: null,
+            refundShare: null,
+            subscriptionPeriod: null,
+            dataSchema: null,
+            maxCreations: null,
+            windowSlots: null,
+            guardian: null,
           })
           .accounts({
             authority: authority.publicKey,
+            delegate: null,
             provider: providerPda,
+            paymentMint: null,
           })
           .instruction()
 
@@ -178,6 +227,7 @@ const init = async () => {
           })
           .accounts({
             authority: authority.publicKey,
+            delegate: null,
             provider: providerPda,
             validator: validatorPda,
             systemProgram: SystemProgram.programId,
@@ -240,18 +290,43 @@ const init = async () => {
           encryptionCount: 0,
           data: authority.publicKey.toBase58(),
           registrationFee: new BN(0),
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
           timestamp,
         })
         .accounts({
           bindie: bindiePda,
           link: linkPda,
           provider: verifierPda,
-          providerTreasury: treasury.publicKey,
+          providerVault: findVaultPda(verifierPda),
           validator: validatorPda,
           signer: validator.publicKey,
           owner: authority.publicKey,
           global: globalPda,
-          treasury: treasury.publicKey,
+          vault: findVaultPda(globalPda),
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: null,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: null,
+          walletEntry: null,
+          prerequisites: null,
+          license: null,
+          licenseTreasury: null,
+          licenseTreasuryTokenAccount: null,
+          escrow: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([validator])
@@ -300,12 +375,16 @@ const init = async () => {
     .updateConfig({
       providerCreationFee: new BN(LAMPORTS_PER_SOL),
       serviceFee: new BN(LAMPORTS_PER_SOL / 100),
-      authority: null,
+      providerCooldown: new BN(60 * 60 * 24 * 30),
       treasury: null,
+      paymentMint: null,
+      maxIntegratorShare: 2000,
+      unpauseTimelock: new BN(60 * 60 * 24),
     })
     .accounts({
       authority: authority.publicKey,
       global: globalPda,
+      paymentMint: null,
     })
     .rpc()
 
@@ -17,6 +17,18 @@ describe('Bindentity Management', () => {
     program.programId,
   )
 
+  const findVaultPda = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('vault'), owner.toBytes()],
+      program.programId,
+    )[0]
+
+  const findEscrowPda = (bindie: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('escrow'), bindie.toBytes()],
+      program.programId,
+    )[0]
+
   let global
   let phoneProviderPda
   let phoneProvider
@@ -105,11 +117,31 @@ describe('Bindentity Management', () => {
       link: linkPda,
       owner: owner.publicKey,
       provider: phoneProviderPda,
-      providerTreasury: phoneProvider.treasury,
+      providerVault: findVaultPda(phoneProviderPda),
       signer: validator.signer,
-      treasury: global.treasury,
+      vault: findVaultPda(globalPda),
       validator: validatorPda,
       global: globalPda,
+      ownerTokenAccount: null,
+      vaultTokenAccount: null,
+      ownerProviderTokenAccount: null,
+      providerVaultTokenAccount: null,
+      tokenProgram: null,
+      pricingRules: null,
+      coupon: null,
+      holderBindie: null,
+      holderLink: null,
+      previousBindie: null,
+      revenueSplit: null,
+      integrator: null,
+      integratorTokenAccount: null,
+      accessList: null,
+      walletEntry: null,
+      prerequisites: null,
+      license: null,
+      licenseTreasury: null,
+      licenseTreasuryTokenAccount: null,
+      escrow: null,
       systemProgram: SystemProgram.programId,
     }
 
@@ -120,6 +152,11 @@ describe('Bindentity Management', () => {
           data: randomPhoneNumber,
           timestamp,
           registrationFee: null,
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
         })
         .accounts(accounts)
         .signers([owner, validatorKp])
@@ -127,6 +164,10 @@ describe('Bindentity Management', () => {
 
       const result = await program.account.bindie.fetch(bindiePda)
       assert.ok(owner.publicKey.equals(result.owner))
+
+      const provider = await program.account.provider.fetch(phoneProviderPda)
+      assert.ok(provider.issuedCount.eq(phoneProvider.issuedCount.addn(1)))
+      assert.ok(provider.liveCount.eq(phoneProvider.liveCount.addn(1)))
     } catch (e) {
       console.log(e)
       throw new Error(e)
@@ -140,9 +181,12 @@ describe('Bindentity Management', () => {
           // allow validator to void
           flags: validator.flags | 4,
           close: null,
+          maxCreations: null,
+          windowSlots: null,
         })
         .accounts({
           authority: phoneProvider.authority,
+          delegate: null,
           provider: phoneProviderPda,
           validator: validatorPda,
         })
@@ -169,9 +213,16 @@ describe('Bindentity Management', () => {
           link: linkPda,
           provider: phoneProviderPda,
           signer: program.provider.publicKey,
-          treasury: phoneProvider.treasury,
+          vault: findVaultPda(globalPda),
           validator: validatorPda,
           validatorSigner: validator.signer,
+          license: null,
+          signerTokenAccount: null,
+          vaultTokenAccount: null,
+          tokenProgram: null,
+          escrow: findEscrowPda(link.bindie),
+          providerVault: null,
+          bindieOwner: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([validatorKp])
@@ -206,10 +257,30 @@ describe('Bindentity Management', () => {
       link: linkPda,
       owner: owner.publicKey,
       provider: phoneProviderPda,
-      providerTreasury: phoneProvider.treasury,
+      providerVault: findVaultPda(phoneProviderPda),
       signer: validator.signer,
-      treasury: global.treasury,
+      vault: findVaultPda(globalPda),
       validator: validatorPda,
+      ownerTokenAccount: null,
+      vaultTokenAccount: null,
+      ownerProviderTokenAccount: null,
+      providerVaultTokenAccount: null,
+      tokenProgram: null,
+      pricingRules: null,
+      coupon: null,
+      holderBindie: null,
+      holderLink: null,
+      previousBindie: null,
+      revenueSplit: null,
+      integrator: null,
+      integratorTokenAccount: null,
+      accessList: null,
+      walletEntry: null,
+      prerequisites: null,
+      license: null,
+      licenseTreasury: null,
+      licenseTreasuryTokenAccount: null,
+      escrow: null,
       systemProgram: SystemProgram.programId,
     }
 
@@ -220,6 +291,11 @@ describe('Bindentity Management', () => {
           data: randomPhoneNumber,
           timestamp,
           registrationFee: null,
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
         })
         .accounts(accounts)
         .signers([owner, validatorKp])
@@ -240,6 +316,7 @@ describe('Bindentity Management', () => {
     )
 
     const link = await program.account.link.fetch(linkPda)
+    const validatorBefore = await program.account.validator.fetch(validatorPda)
 
     try {
       await program.methods
@@ -252,9 +329,16 @@ describe('Bindentity Management', () => {
           link: linkPda,
           provider: phoneProviderPda,
           signer: owner.publicKey,
-          treasury: phoneProvider.treasury,
+          vault: findVaultPda(globalPda),
           validator: validatorPda,
           validatorSigner: validator.signer,
+          license: null,
+          signerTokenAccount: null,
+          vaultTokenAccount: null,
+          tokenProgram: null,
+          escrow: findEscrowPda(link.bindie),
+          providerVault: null,
+          bindieOwner: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([owner, validatorKp])
@@ -263,5 +347,88 @@ describe('Bindentity Management', () => {
       console.log(e)
       throw new Error(e)
     }
+
+    // voided by the owner, so it is not counted towards the validator
+    const validatorAfter = await program.account.validator.fetch(validatorPda)
+    assert.ok(validatorAfter.voidedCount.eq(validatorBefore.voidedCount))
+  })
+
+  it('should share the service fee with the integrator', async () => {
+    const timestamp = new anchor.BN(Math.floor(new Date().getTime() / 1000))
+    const phoneNumber = Buffer.from(
+      Math.floor(Math.random() * 100_000_000_000) + '',
+    )
+    const integrator = Keypair.generate()
+
+    const [bindiePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('bindie'),
+        Buffer.from(timestamp + ''),
+        phoneProviderPda.toBytes(),
+        phoneNumber,
+      ],
+      program.programId,
+    )
+
+    const [linkPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('link'), phoneProviderPda.toBytes(), phoneNumber],
+      program.programId,
+    )
+
+    await program.methods
+      .createBindie({
+        encryptionCount: 1,
+        data: phoneNumber,
+        timestamp,
+        registrationFee: null,
+        maxFee: null,
+        integratorShare: global.maxIntegratorShare,
+        reveal: null,
+        allowlistProof: null,
+        couponCode: null,
+      })
+      .accounts({
+        global: globalPda,
+        bindie: bindiePda,
+        link: linkPda,
+        owner: owner.publicKey,
+        provider: phoneProviderPda,
+        providerVault: findVaultPda(phoneProviderPda),
+        signer: validator.signer,
+        vault: findVaultPda(globalPda),
+        validator: validatorPda,
+        ownerTokenAccount: null,
+        vaultTokenAccount: null,
+        ownerProviderTokenAccount: null,
+        providerVaultTokenAccount: null,
+        tokenProgram: null,
+        pricingRules: null,
+        coupon: null,
+        holderBindie: null,
+        holderLink: null,
+        previousBindie: null,
+        revenueSplit: null,
+        integrator: integrator.publicKey,
+        integratorTokenAccount: null,
+        accessList: null,
+        walletEntry: null,
+        prerequisites: null,
+        license: null,
+        licenseTreasury: null,
+        licenseTreasuryTokenAccount: null,
+        escrow: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner, validatorKp, integrator])
+      .rpc()
+
+    const balance = await program.provider.connection.getBalance(
+      integrator.publicKey,
+    )
+
+    assert.strictEqual(
+      balance,
+      global.serviceFee.muln(global.maxIntegratorShare).divn(10000).toNumber(),
+    )
   })
 })
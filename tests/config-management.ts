@@ -0,0 +1,210 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorError, Program } from '@coral-xyz/anchor'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { assert } from 'chai'
+import { Bindentity } from '../target/types/bindentity'
+import authorityJSON from '../keys/authority.json'
+import { airdrop } from '../scripts/utils'
+
+describe('Config Management', () => {
+  anchor.setProvider(anchor.AnchorProvider.env())
+
+  const program = anchor.workspace.Bindentity as Program<Bindentity>
+  const authority = Keypair.fromSecretKey(new Uint8Array(authorityJSON))
+
+  const [globalPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('global')],
+    program.programId,
+  )
+
+  let newAuthority: Keypair
+  let stranger: Keypair
+
+  const updateConfig = (signer: Keypair) =>
+    program.methods
+      .updateConfig({
+        providerCreationFee: null,
+        serviceFee: null,
+        providerCooldown: null,
+        treasury: null,
+        paymentMint: null,
+        maxIntegratorShare: null,
+        unpauseTimelock: null,
+      })
+      .accounts({
+        authority: signer.publicKey,
+        global: globalPda,
+        paymentMint: null,
+      })
+      .signers([signer])
+      .rpc()
+
+  before(async () => {
+    const global = await program.account.global.fetchNullable(globalPda)
+
+    if (!global) {
+      throw new Error(
+        'Global config not found, please run `anchor run init` to initialize.',
+      )
+    }
+
+    if (!global.authority.equals(authority.publicKey)) {
+      throw new Error('Global config authority did not match')
+    }
+
+    newAuthority = Keypair.generate()
+    stranger = Keypair.generate()
+
+    await airdrop(program.provider.connection, newAuthority.publicKey)
+    await airdrop(program.provider.connection, stranger.publicKey)
+  })
+
+  it('should clear the proposed authority once cancelled', async () => {
+    await program.methods
+      .proposeConfigAuthority({
+        pendingAuthority: newAuthority.publicKey,
+      })
+      .accounts({
+        global: globalPda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc()
+
+    let global = await program.account.global.fetch(globalPda)
+
+    assert.ok(global.pendingAuthority.equals(newAuthority.publicKey))
+
+    await program.methods
+      .cancelConfigAuthority()
+      .accounts({
+        global: globalPda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc()
+
+    global = await program.account.global.fetch(globalPda)
+
+    assert.ok(global.pendingAuthority.equals(PublicKey.default))
+    assert.ok(global.authority.equals(authority.publicKey))
+
+    // the cancelled proposal can no longer be accepted
+    try {
+      await program.methods
+        .acceptConfigAuthority()
+        .accounts({
+          global: globalPda,
+          pendingAuthority: newAuthority.publicKey,
+        })
+        .signers([newAuthority])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'NotPendingAuthority')
+    }
+  })
+
+  it('should only let the proposed authority accept the config', async () => {
+    await program.methods
+      .proposeConfigAuthority({
+        pendingAuthority: newAuthority.publicKey,
+      })
+      .accounts({
+        global: globalPda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc()
+
+    try {
+      await program.methods
+        .acceptConfigAuthority()
+        .accounts({
+          global: globalPda,
+          pendingAuthority: stranger.publicKey,
+        })
+        .signers([stranger])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'NotPendingAuthority')
+    }
+
+    await program.methods
+      .acceptConfigAuthority()
+      .accounts({
+        global: globalPda,
+        pendingAuthority: newAuthority.publicKey,
+      })
+      .signers([newAuthority])
+      .rpc()
+
+    const global = await program.account.global.fetch(globalPda)
+
+    assert.ok(global.authority.equals(newAuthority.publicKey))
+    assert.ok(global.pendingAuthority.equals(PublicKey.default))
+  })
+
+  it('should not let the previous authority update the config', async () => {
+    try {
+      await updateConfig(authority)
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'ConstraintRaw')
+    }
+
+    try {
+      await program.methods
+        .proposeConfigAuthority({
+          pendingAuthority: authority.publicKey,
+        })
+        .accounts({
+          global: globalPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'ConstraintRaw')
+    }
+
+    await updateConfig(newAuthority)
+  })
+
+  after(async () => {
+    // hand the config back, so that the other suites keep their authority
+    const global = await program.account.global.fetch(globalPda)
+
+    if (!global.authority.equals(newAuthority.publicKey)) return
+
+    await program.methods
+      .proposeConfigAuthority({
+        pendingAuthority: authority.publicKey,
+      })
+      .accounts({
+        global: globalPda,
+        authority: newAuthority.publicKey,
+      })
+      .signers([newAuthority])
+      .rpc()
+
+    await program.methods
+      .acceptConfigAuthority()
+      .accounts({
+        global: globalPda,
+        pendingAuthority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc()
+  })
+})
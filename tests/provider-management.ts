@@ -1,5 +1,5 @@
 import * as anchor from '@coral-xyz/anchor'
-import { Program } from '@coral-xyz/anchor'
+import { AnchorError, Program } from '@coral-xyz/anchor'
 import {
   Keypair,
   PublicKey,
@@ -9,8 +9,10 @@ import {
   Transaction,
 } from '@solana/web3.js'
 import { BN } from 'bn.js'
+import { createHash } from 'crypto'
 import { assert } from 'chai'
 import { Bindentity } from '../target/types/bindentity'
+import validatorJSON from '../keys/validator.json'
 import { airdrop } from '../scripts/utils'
 
 describe('Provider Management', () => {
@@ -23,6 +25,18 @@ describe('Provider Management', () => {
     program.programId,
   )
 
+  const findVaultPda = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('vault'), owner.toBytes()],
+      program.programId,
+    )[0]
+
+  const findEscrowPda = (bindie: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('escrow'), bindie.toBytes()],
+      program.programId,
+    )[0]
+
   const providerOwner = Keypair.generate()
   const validatorSigner = Keypair.generate()
   const user = Keypair.generate()
@@ -33,6 +47,11 @@ describe('Provider Management', () => {
     program.programId,
   )
 
+  const [tombstonePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('tombstone'), Buffer.from(bindentityName, 'utf-8')],
+    program.programId,
+  )
+
   const [validatorPda] = PublicKey.findProgramAddressSync(
     [
       Buffer.from('validator'),
@@ -42,6 +61,117 @@ describe('Provider Management', () => {
     program.programId,
   )
 
+  // the `provider` provider and its validator, created by `anchor run init`
+  const verifierSigner = Keypair.fromSecretKey(new Uint8Array(validatorJSON))
+
+  const [verifierPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('provider'), Buffer.from('provider')],
+    program.programId,
+  )
+
+  const [verifierValidatorPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('validator'),
+      verifierPda.toBytes(),
+      verifierSigner.publicKey.toBytes(),
+    ],
+    program.programId,
+  )
+
+  const verifierData = providerOwner.publicKey.toBase58()
+
+  const [verifierLinkPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('link'),
+      verifierPda.toBytes(),
+      Buffer.from(verifierData.substring(0, 32), 'utf-8'),
+    ],
+    program.programId,
+  )
+
+  // registers a `provider` bindie for the provider owner and verifies the provider with it
+  const verifyProvider = async (timestamp: BN) => {
+    const [bindiePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('bindie'),
+        Buffer.from(timestamp + ''),
+        verifierPda.toBytes(),
+        Buffer.from(verifierData.substring(0, 32), 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    await program.methods
+      .createBindie({
+        data: verifierData,
+        encryptionCount: 0,
+        registrationFee: new BN(0),
+        maxFee: null,
+        integratorShare: null,
+        reveal: null,
+        allowlistProof: null,
+        couponCode: null,
+        timestamp,
+      })
+      .accounts({
+        global: globalPda,
+        bindie: bindiePda,
+        link: verifierLinkPda,
+        owner: providerOwner.publicKey,
+        provider: verifierPda,
+        providerVault: findVaultPda(verifierPda),
+        signer: verifierSigner.publicKey,
+        vault: findVaultPda(globalPda),
+        validator: verifierValidatorPda,
+        ownerTokenAccount: null,
+        vaultTokenAccount: null,
+        ownerProviderTokenAccount: null,
+        providerVaultTokenAccount: null,
+        tokenProgram: null,
+        pricingRules: null,
+        coupon: null,
+        holderBindie: null,
+        holderLink: null,
+        previousBindie: null,
+        revenueSplit: null,
+        integrator: null,
+        integratorTokenAccount: null,
+        accessList: null,
+        walletEntry: null,
+        prerequisites: null,
+        license: null,
+        licenseTreasury: null,
+        licenseTreasuryTokenAccount: null,
+        escrow: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner, verifierSigner])
+      .rpc()
+
+    await program.methods
+      .verifyProvider({
+        data: verifierData,
+      })
+      .accounts({
+        targetProvider: providerPda,
+        owner: providerOwner.publicKey,
+        ownerBindie: bindiePda,
+        ownerLink: verifierLinkPda,
+        verifierProvider: verifierPda,
+        validator: verifierValidatorPda,
+        signer: verifierSigner.publicKey,
+      })
+      .signers([providerOwner, verifierSigner])
+      .rpc()
+
+    const provider = await program.account.provider.fetch(providerPda)
+
+    assert.ok((provider.flags & 4) === 4)
+    assert.ok(provider.verifiedBindie.equals(bindiePda))
+
+    return bindiePda
+  }
+
   before(async () => {
     const connection = program.provider.connection
 
@@ -69,8 +199,6 @@ describe('Provider Management', () => {
   })
 
   it('should create a new bindentity provider', async () => {
-    const global = await program.account.global.fetch(globalPda)
-
     try {
       await program.methods
         .createProvider({
@@ -82,7 +210,15 @@ describe('Provider Management', () => {
           global: globalPda,
           owner: providerOwner.publicKey,
           provider: providerPda,
-          treasury: global.treasury,
+          providerVault: findVaultPda(providerPda),
+          tombstone: tombstonePda,
+          vault: findVaultPda(globalPda),
+          parent: null,
+          approval: null,
+          approvalPayer: null,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          tokenProgram: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([providerOwner])
@@ -97,6 +233,107 @@ describe('Provider Management', () => {
     assert.equal(provider.name, bindentityName)
   })
 
+  it('should only create a sub-namespace approved by the parent', async () => {
+    const childOwner = Keypair.generate()
+    const childName = bindentityName + '/sub'
+
+    // provider creation fee is 1 SOL, so we need more than 1 SOL
+    await airdrop(program.provider.connection, childOwner.publicKey)
+    await airdrop(program.provider.connection, childOwner.publicKey)
+
+    const [childPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('provider'), Buffer.from(childName, 'utf-8')],
+      program.programId,
+    )
+
+    const [childTombstonePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('tombstone'), Buffer.from(childName, 'utf-8')],
+      program.programId,
+    )
+
+    const [approvalPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('sub_provider_approval'),
+        providerPda.toBytes(),
+        Buffer.from(childName, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const createChild = (approval: PublicKey | null) =>
+      program.methods
+        .createProvider({
+          name: childName,
+          registrationFee: new BN(0),
+          providerTreasury: childOwner.publicKey,
+        })
+        .accounts({
+          global: globalPda,
+          owner: childOwner.publicKey,
+          provider: childPda,
+          providerVault: findVaultPda(childPda),
+          tombstone: childTombstonePda,
+          vault: findVaultPda(globalPda),
+          parent: providerPda,
+          approval,
+          approvalPayer: approval && providerOwner.publicKey,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([childOwner])
+        .rpc()
+
+    try {
+      await createChild(null)
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'SubProviderUnauthorized')
+    }
+
+    await program.methods
+      .approveSubProvider({
+        name: childName,
+        owner: childOwner.publicKey,
+      })
+      .accounts({
+        approval: approvalPda,
+        parent: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    // the rent of the consumed approval returns to the parent's authority
+    const connection = program.provider.connection
+    const approvalRent = await connection.getBalance(approvalPda)
+    const authorityBalance = await connection.getBalance(
+      providerOwner.publicKey,
+    )
+
+    await createChild(approvalPda)
+
+    const child = await program.account.provider.fetch(childPda)
+
+    assert.ok(child.parent.equals(providerPda))
+    // the parent cannot be closed while its sub-namespaces exist
+    assert.strictEqual(
+      (await program.account.provider.fetch(providerPda)).childCount,
+      1,
+    )
+    assert.isNull(
+      await program.account.subProviderApproval.fetchNullable(approvalPda),
+    )
+    assert.strictEqual(
+      await connection.getBalance(providerOwner.publicKey),
+      authorityBalance + approvalRent,
+    )
+  })
+
   it('should add a validator', async () => {
     try {
       await program.methods
@@ -106,6 +343,7 @@ describe('Provider Management', () => {
         })
         .accounts({
           authority: providerOwner.publicKey,
+          delegate: null,
           provider: providerPda,
           validator: validatorPda,
           systemProgram: SystemProgram.programId,
@@ -141,15 +379,17 @@ describe('Provider Management', () => {
       program.programId,
     )
 
-    const global = await program.account.global.fetch(globalPda)
-    const provider = await program.account.provider.fetch(providerPda)
-
     try {
       await program.methods
         .createBindie({
           data,
           encryptionCount: 0,
           registrationFee: new BN(0),
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
           timestamp,
         })
         .accounts({
@@ -158,10 +398,30 @@ describe('Provider Management', () => {
           link: linkPda,
           owner: user.publicKey,
           provider: providerPda,
-          providerTreasury: provider.treasury,
+          providerVault: findVaultPda(providerPda),
           signer: validatorSigner.publicKey,
-          treasury: global.treasury,
+          vault: findVaultPda(globalPda),
           validator: validatorPda,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: null,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: null,
+          walletEntry: null,
+          prerequisites: null,
+          license: null,
+          licenseTreasury: null,
+          licenseTreasuryTokenAccount: null,
+          escrow: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([user, validatorSigner])
@@ -185,9 +445,55 @@ describe('Provider Management', () => {
       await program.methods
         .createProviderMetadata({
           uri,
+          contentHash: null,
+          displayName: bindentityName,
+          registrationUrl: uri,
+          tags: ['sample'],
+        })
+        .accounts({
+          authority: providerOwner.publicKey,
+          delegate: null,
+          provider: providerPda,
+          providerMetadata: providerMetadataPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([providerOwner])
+        .rpc()
+
+      const metadata = await program.account.providerMetadata.fetch(
+        providerMetadataPda,
+      )
+
+      assert.ok(metadata.uri === uri)
+      assert.ok(metadata.displayName === bindentityName)
+      assert.deepEqual(metadata.tags, ['sample'])
+    } catch (e) {
+      console.log(e)
+      throw new Error(e)
+    }
+  })
+
+  it('should be able to update the metadata of a provider', async () => {
+    const uri = `http://example.com/updated.json`
+    const contentHash = Array.from({ length: 32 }, (_, i) => i)
+
+    const [providerMetadataPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('provider_metadata', 'utf-8'), providerPda.toBytes()],
+      program.programId,
+    )
+
+    try {
+      await program.methods
+        .updateProviderMetadata({
+          uri,
+          contentHash,
+          displayName: 'Sample Bindentity',
+          registrationUrl: 'http://example.com/register',
+          tags: ['sample', 'test'],
         })
         .accounts({
           authority: providerOwner.publicKey,
+          delegate: null,
           provider: providerPda,
           providerMetadata: providerMetadataPda,
           systemProgram: SystemProgram.programId,
@@ -200,6 +506,8 @@ describe('Provider Management', () => {
       )
 
       assert.ok(metadata.uri === uri)
+      assert.deepEqual(metadata.contentHash, contentHash)
+      assert.deepEqual(metadata.tags, ['sample', 'test'])
     } catch (e) {
       console.log(e)
       throw new Error(e)
@@ -211,15 +519,26 @@ describe('Provider Management', () => {
       await program.methods
         .updateProvider({
           published: true,
-          authority: null,
           registrationFee: null,
           treasury: null,
+          paymentMint: null,
+          minCustomFee: null,
+          maxCustomFee: null,
+          refundWindow: null,
+          refundShare: null,
+          subscriptionPeriod: null,
+          dataSchema: null,
+          maxCreations: null,
+          windowSlots: null,
+          guardian: null,
           forSale: null,
           sellingPrice: null,
         })
         .accounts({
           authority: providerOwner.publicKey,
+          delegate: null,
           provider: providerPda,
+          paymentMint: null,
         })
         .signers([providerOwner])
         .rpc()
@@ -229,15 +548,220 @@ describe('Provider Management', () => {
     }
   })
 
+  it('should not set a payment mint without its mint account', async () => {
+    try {
+      await program.methods
+        .updateProvider({
+          published: null,
+          registrationFee: null,
+          treasury: null,
+          paymentMint: Keypair.generate().publicKey,
+          minCustomFee: null,
+          maxCustomFee: null,
+          refundWindow: null,
+          refundShare: null,
+          subscriptionPeriod: null,
+          dataSchema: null,
+          maxCreations: null,
+          windowSlots: null,
+          guardian: null,
+          forSale: null,
+          sellingPrice: null,
+        })
+        .accounts({
+          authority: providerOwner.publicKey,
+          delegate: null,
+          provider: providerPda,
+          paymentMint: null,
+        })
+        .signers([providerOwner])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'InvalidPaymentAccount')
+    }
+  })
+
+  it('should unverify a provider only once its backing bindie is void', async () => {
+    await airdrop(program.provider.connection, providerOwner.publicKey)
+
+    const bindiePda = await verifyProvider(
+      new BN(Math.floor(new Date().getTime() / 1000)),
+    )
+
+    const unverify = () =>
+      program.methods
+        .unverifyProvider({
+          data: verifierData,
+        })
+        .accounts({
+          verifierProvider: verifierPda,
+          validator: null,
+          signer: null,
+          verifiedBindie: bindiePda,
+          verifiedLink: verifierLinkPda,
+          targetProvider: providerPda,
+        })
+        .rpc()
+
+    try {
+      await unverify()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'VerificationStillValid')
+    }
+
+    // the owner voids the backing bindie
+    await program.methods
+      .voidBindie({
+        data: null,
+      })
+      .accounts({
+        global: globalPda,
+        bindie: bindiePda,
+        link: verifierLinkPda,
+        provider: verifierPda,
+        signer: providerOwner.publicKey,
+        vault: findVaultPda(globalPda),
+        validator: verifierValidatorPda,
+        validatorSigner: verifierSigner.publicKey,
+        license: null,
+        signerTokenAccount: null,
+        vaultTokenAccount: null,
+        tokenProgram: null,
+        escrow: findEscrowPda(bindiePda),
+        providerVault: null,
+        bindieOwner: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner, verifierSigner])
+      .rpc()
+
+    await unverify()
+
+    const provider = await program.account.provider.fetch(providerPda)
+
+    assert.ok((provider.flags & 4) === 0)
+    assert.ok(provider.verifiedBindie.equals(PublicKey.default))
+  })
+
+  it('should unverify a provider only through a validator permitted to void', async () => {
+    // the bindie of the previous test is void, so register a new one
+    const bindiePda = await verifyProvider(
+      new BN(Math.floor(new Date().getTime() / 1000) + 1),
+    )
+
+    const unverify = (validator: PublicKey, signer: Keypair) =>
+      program.methods
+        .unverifyProvider({
+          data: null,
+        })
+        .accounts({
+          verifierProvider: verifierPda,
+          validator,
+          signer: signer.publicKey,
+          verifiedBindie: null,
+          verifiedLink: null,
+          targetProvider: providerPda,
+        })
+        .signers([signer])
+        .rpc()
+
+    // the initial validator is not permitted to void
+    try {
+      await unverify(verifierValidatorPda, verifierSigner)
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'UnverifyUnauthorized')
+    }
+
+    // the wallet is the authority of the `provider` provider
+    const voidingSigner = Keypair.generate()
+
+    const [voidingValidatorPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('validator'),
+        verifierPda.toBytes(),
+        voidingSigner.publicKey.toBytes(),
+      ],
+      program.programId,
+    )
+
+    await program.methods
+      .createValidator({
+        enabled: true,
+        signer: voidingSigner.publicKey,
+      })
+      .accounts({
+        authority: program.provider.publicKey,
+        delegate: null,
+        provider: verifierPda,
+        validator: voidingValidatorPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc()
+
+    const updateVoidingValidator = (flags: number | null, close: boolean) =>
+      program.methods
+        .updateValidator({
+          flags,
+          close,
+          maxCreations: null,
+          windowSlots: null,
+        })
+        .accounts({
+          authority: program.provider.publicKey,
+          delegate: null,
+          provider: verifierPda,
+          validator: voidingValidatorPda,
+        })
+        .rpc()
+
+    // permitted to distribute and void
+    await updateVoidingValidator(5, false)
+
+    // the signer must be the one of the validator
+    try {
+      await unverify(voidingValidatorPda, verifierSigner)
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'UnverifyUnauthorized')
+    }
+
+    await unverify(voidingValidatorPda, voidingSigner)
+
+    const provider = await program.account.provider.fetch(providerPda)
+
+    assert.ok((provider.flags & 4) === 0)
+    assert.ok(provider.verifiedBindie.equals(PublicKey.default))
+
+    // the verification bindie itself is still live
+    const link = await program.account.link.fetch(verifierLinkPda)
+    assert.ok(link.bindie.equals(bindiePda))
+
+    await updateVoidingValidator(null, true)
+  })
+
   it('should not allow the user to register when using a disabled validator', async () => {
     // disable validator
     await program.methods
       .updateValidator({
         flags: 0,
         close: null,
+        maxCreations: null,
+        windowSlots: null,
       })
       .accounts({
         authority: providerOwner.publicKey,
+        delegate: null,
         provider: providerPda,
         validator: validatorPda,
       })
@@ -265,15 +789,17 @@ describe('Provider Management', () => {
       program.programId,
     )
 
-    const global = await program.account.global.fetch(globalPda)
-    const provider = await program.account.provider.fetch(providerPda)
-
     try {
       await program.methods
         .createBindie({
           data,
           encryptionCount: 0,
           registrationFee: new BN(0),
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
           timestamp,
         })
         .accounts({
@@ -282,10 +808,30 @@ describe('Provider Management', () => {
           link: linkPda,
           owner: user.publicKey,
           provider: providerPda,
-          providerTreasury: provider.treasury,
+          providerVault: findVaultPda(providerPda),
           signer: validatorSigner.publicKey,
-          treasury: global.treasury,
+          vault: findVaultPda(globalPda),
           validator: validatorPda,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: null,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: null,
+          walletEntry: null,
+          prerequisites: null,
+          license: null,
+          licenseTreasury: null,
+          licenseTreasuryTokenAccount: null,
+          escrow: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([user, validatorSigner])
@@ -305,9 +851,12 @@ describe('Provider Management', () => {
         .updateValidator({
           flags: validator.flags | 7,
           close: null,
+          maxCreations: null,
+          windowSlots: null,
         })
         .accounts({
           authority: providerOwner.publicKey,
+          delegate: null,
           provider: providerPda,
           validator: validatorPda,
         })
@@ -319,8 +868,35 @@ describe('Provider Management', () => {
     }
   })
 
-  it('should allow user to register to the new bindentity', async () => {
-    const data = 'sample_user_id'
+  it('should not allow a validator to charge outside of the custom fee bounds', async () => {
+    await program.methods
+      .updateProvider({
+        published: null,
+        registrationFee: null,
+        treasury: null,
+        paymentMint: null,
+        minCustomFee: null,
+        maxCustomFee: new BN(1000),
+        refundWindow: null,
+        refundShare: null,
+        subscriptionPeriod: null,
+        dataSchema: null,
+        maxCreations: null,
+        windowSlots: null,
+        guardian: null,
+        forSale: null,
+        sellingPrice: null,
+      })
+      .accounts({
+        authority: providerOwner.publicKey,
+        delegate: null,
+        provider: providerPda,
+        paymentMint: null,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const data = 'overcharged_user_id'
     const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
     const [identityPda] = PublicKey.findProgramAddressSync(
       [
@@ -341,15 +917,17 @@ describe('Provider Management', () => {
       program.programId,
     )
 
-    const global = await program.account.global.fetch(globalPda)
-    const provider = await program.account.provider.fetch(providerPda)
-
     try {
       await program.methods
         .createBindie({
           data,
           encryptionCount: 0,
-          registrationFee: new BN(0),
+          registrationFee: new BN(2000),
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
           timestamp,
         })
         .accounts({
@@ -358,17 +936,2443 @@ describe('Provider Management', () => {
           link: linkPda,
           owner: user.publicKey,
           provider: providerPda,
-          providerTreasury: provider.treasury,
+          providerVault: findVaultPda(providerPda),
           signer: validatorSigner.publicKey,
-          treasury: global.treasury,
+          vault: findVaultPda(globalPda),
           validator: validatorPda,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: null,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: null,
+          walletEntry: null,
+          prerequisites: null,
+          license: null,
+          licenseTreasury: null,
+          licenseTreasuryTokenAccount: null,
+          escrow: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([user, validatorSigner])
         .rpc()
-    } catch (e) {
-      console.log(e)
-      throw new Error(e)
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'CustomFeeOutOfBounds')
     }
   })
+
+  it('should allow user to register to the new bindentity', async () => {
+    const data = 'sample_user_id'
+    const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+    const [identityPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('bindie', 'utf-8'),
+        Buffer.from(timestamp + '', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const [linkPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('link', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    try {
+      await program.methods
+        .createBindie({
+          data,
+          encryptionCount: 0,
+          registrationFee: new BN(0),
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
+          timestamp,
+        })
+        .accounts({
+          global: globalPda,
+          bindie: identityPda,
+          link: linkPda,
+          owner: user.publicKey,
+          provider: providerPda,
+          providerVault: findVaultPda(providerPda),
+          signer: validatorSigner.publicKey,
+          vault: findVaultPda(globalPda),
+          validator: validatorPda,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: null,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: null,
+          walletEntry: null,
+          prerequisites: null,
+          license: null,
+          licenseTreasury: null,
+          licenseTreasuryTokenAccount: null,
+          escrow: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user, validatorSigner])
+        .rpc()
+    } catch (e) {
+      console.log(e)
+      throw new Error(e)
+    }
+  })
+
+  it('should split the registration fee across the revenue split recipients', async () => {
+    const partner = Keypair.generate()
+    await airdrop(program.provider.connection, partner.publicKey)
+
+    const [revenueSplitPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('revenue_split'), providerPda.toBytes()],
+      program.programId,
+    )
+
+    await program.methods
+      .createRevenueSplit({
+        recipients: [
+          { recipient: providerOwner.publicKey, share: 7000 },
+          { recipient: partner.publicKey, share: 3000 },
+        ],
+      })
+      .accounts({
+        revenueSplit: revenueSplitPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const data = 'split_user_id'
+    const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+    const [identityPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('bindie', 'utf-8'),
+        Buffer.from(timestamp + '', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const [linkPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('link', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const partnerBalance = await program.provider.connection.getBalance(
+      partner.publicKey,
+    )
+
+    await program.methods
+      .createBindie({
+        data,
+        encryptionCount: 0,
+        registrationFee: new BN(1000),
+        maxFee: null,
+        integratorShare: null,
+        reveal: null,
+        allowlistProof: null,
+        couponCode: null,
+        timestamp,
+      })
+      .accounts({
+        global: globalPda,
+        bindie: identityPda,
+        link: linkPda,
+        owner: user.publicKey,
+        provider: providerPda,
+        providerVault: findVaultPda(providerPda),
+        signer: validatorSigner.publicKey,
+        vault: findVaultPda(globalPda),
+        validator: validatorPda,
+        ownerTokenAccount: null,
+        vaultTokenAccount: null,
+        ownerProviderTokenAccount: null,
+        providerVaultTokenAccount: null,
+        tokenProgram: null,
+        pricingRules: null,
+        coupon: null,
+        holderBindie: null,
+        holderLink: null,
+        previousBindie: null,
+        revenueSplit: revenueSplitPda,
+        integrator: null,
+        integratorTokenAccount: null,
+        accessList: null,
+        walletEntry: null,
+        prerequisites: null,
+        license: null,
+        licenseTreasury: null,
+        licenseTreasuryTokenAccount: null,
+        escrow: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        { pubkey: providerOwner.publicKey, isSigner: false, isWritable: true },
+        { pubkey: partner.publicKey, isSigner: false, isWritable: true },
+      ])
+      .signers([user, validatorSigner])
+      .rpc()
+
+    assert.strictEqual(
+      await program.provider.connection.getBalance(partner.publicKey),
+      partnerBalance + 300,
+    )
+
+    await program.methods
+      .removeRevenueSplit()
+      .accounts({
+        revenueSplit: revenueSplitPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+  })
+
+  it('should redeem a coupon only up to its limit', async () => {
+    const code = 'promo'
+    // only the hash of the code is stored on chain
+    const codeHash = createHash('sha256')
+      .update(Buffer.concat([providerPda.toBytes(), Buffer.from(code)]))
+      .digest()
+    const [couponPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('coupon'), providerPda.toBytes(), codeHash],
+      program.programId,
+    )
+
+    await program.methods
+      .createCoupon({
+        codeHash: [...codeHash],
+        discount: 10000,
+        maxRedemptions: 1,
+        expiresAt: new BN(0),
+      })
+      .accounts({
+        coupon: couponPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const register = async (data: string, couponCode = code) => {
+      const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+      const [identityPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('bindie', 'utf-8'),
+          Buffer.from(timestamp + '', 'utf-8'),
+          providerPda.toBytes(),
+          Buffer.from(data, 'utf-8'),
+        ],
+        program.programId,
+      )
+
+      const [linkPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('link', 'utf-8'),
+          providerPda.toBytes(),
+          Buffer.from(data, 'utf-8'),
+        ],
+        program.programId,
+      )
+
+      await program.methods
+        .createBindie({
+          data,
+          encryptionCount: 0,
+          registrationFee: null,
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode,
+          timestamp,
+        })
+        .accounts({
+          global: globalPda,
+          bindie: identityPda,
+          link: linkPda,
+          owner: user.publicKey,
+          provider: providerPda,
+          providerVault: findVaultPda(providerPda),
+          signer: validatorSigner.publicKey,
+          vault: findVaultPda(globalPda),
+          validator: validatorPda,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: couponPda,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: null,
+          walletEntry: null,
+          prerequisites: null,
+          license: null,
+          licenseTreasury: null,
+          licenseTreasuryTokenAccount: null,
+          escrow: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user, validatorSigner])
+        .rpc()
+    }
+
+    try {
+      await register('coupon_user_id', 'not_the_code')
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'InvalidCouponCode')
+    }
+
+    await register('coupon_user_id')
+
+    try {
+      await register('another_coupon_user_id')
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'CouponUnavailable')
+    }
+
+    const coupon = await program.account.coupon.fetch(couponPda)
+    assert.strictEqual(coupon.redemptionCount, 1)
+    assert.deepEqual(coupon.codeHash, [...codeHash])
+  })
+
+  it('should collect registration fees in the provider vault until withdrawn', async () => {
+    const data = 'vault_user_id'
+    const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+    const [identityPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('bindie', 'utf-8'),
+        Buffer.from(timestamp + '', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const [linkPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('link', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const connection = program.provider.connection
+    const providerVaultPda = findVaultPda(providerPda)
+    const vaultBalance = await connection.getBalance(providerVaultPda)
+
+    await program.methods
+      .createBindie({
+        data,
+        encryptionCount: 0,
+        registrationFee: new BN(1000),
+        maxFee: null,
+        integratorShare: null,
+        reveal: null,
+        allowlistProof: null,
+        couponCode: null,
+        timestamp,
+      })
+      .accounts({
+        global: globalPda,
+        bindie: identityPda,
+        link: linkPda,
+        owner: user.publicKey,
+        provider: providerPda,
+        providerVault: providerVaultPda,
+        signer: validatorSigner.publicKey,
+        vault: findVaultPda(globalPda),
+        validator: validatorPda,
+        ownerTokenAccount: null,
+        vaultTokenAccount: null,
+        ownerProviderTokenAccount: null,
+        providerVaultTokenAccount: null,
+        tokenProgram: null,
+        pricingRules: null,
+        coupon: null,
+        holderBindie: null,
+        holderLink: null,
+        previousBindie: null,
+        revenueSplit: null,
+        integrator: null,
+        integratorTokenAccount: null,
+        accessList: null,
+        walletEntry: null,
+        prerequisites: null,
+        license: null,
+        licenseTreasury: null,
+        licenseTreasuryTokenAccount: null,
+        escrow: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user, validatorSigner])
+      .rpc()
+
+    assert.strictEqual(
+      await connection.getBalance(providerVaultPda),
+      vaultBalance + 1000,
+    )
+
+    const withdraw = (authority: Keypair) =>
+      program.methods
+        .withdrawProviderFees({
+          amount: new BN(1000),
+        })
+        .accounts({
+          providerVault: providerVaultPda,
+          providerTreasury: providerOwner.publicKey,
+          provider: providerPda,
+          delegate: null,
+          authority: authority.publicKey,
+          providerVaultTokenAccount: null,
+          providerTreasuryTokenAccount: null,
+          tokenProgram: null,
+        })
+        .signers([authority])
+        .rpc()
+
+    try {
+      await withdraw(validatorSigner)
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'RoleUnauthorized')
+    }
+
+    await withdraw(providerOwner)
+
+    assert.strictEqual(
+      await connection.getBalance(providerVaultPda),
+      vaultBalance,
+    )
+  })
+
+  it('should partially refund the registration fee when voided within the refund window', async () => {
+    const updateRefundPolicy = (refundWindow: number, refundShare: number) =>
+      program.methods
+        .updateProvider({
+          published: null,
+          registrationFee: null,
+          treasury: null,
+          paymentMint: null,
+          minCustomFee: null,
+          maxCustomFee: null,
+          refundWindow: new BN(refundWindow),
+          refundShare,
+          subscriptionPeriod: null,
+          dataSchema: null,
+          maxCreations: null,
+          windowSlots: null,
+          guardian: null,
+          forSale: null,
+          sellingPrice: null,
+        })
+        .accounts({
+          authority: providerOwner.publicKey,
+          delegate: null,
+          provider: providerPda,
+          paymentMint: null,
+        })
+        .signers([providerOwner])
+        .rpc()
+
+    // no escrow is created for a fee which is not refundable
+    const unescrowedData = 'unescrowed_user_id'
+    const unescrowedTimestamp = new BN(Math.floor(new Date().getTime() / 1000))
+    const [unescrowedPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('bindie', 'utf-8'),
+        Buffer.from(unescrowedTimestamp + '', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(unescrowedData, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    try {
+      await program.methods
+        .createBindie({
+          data: unescrowedData,
+          encryptionCount: 0,
+          registrationFee: null,
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
+          timestamp: unescrowedTimestamp,
+        })
+        .accounts({
+          global: globalPda,
+          bindie: unescrowedPda,
+          link: PublicKey.findProgramAddressSync(
+            [
+              Buffer.from('link', 'utf-8'),
+              providerPda.toBytes(),
+              Buffer.from(unescrowedData, 'utf-8'),
+            ],
+            program.programId,
+          )[0],
+          owner: user.publicKey,
+          provider: providerPda,
+          providerVault: findVaultPda(providerPda),
+          signer: validatorSigner.publicKey,
+          vault: findVaultPda(globalPda),
+          validator: validatorPda,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: null,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: null,
+          walletEntry: null,
+          prerequisites: null,
+          license: null,
+          licenseTreasury: null,
+          licenseTreasuryTokenAccount: null,
+          escrow: findEscrowPda(unescrowedPda),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user, validatorSigner])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'MissingEscrowAccount')
+    }
+
+    await updateRefundPolicy(60 * 60, 5000)
+
+    // the escrowed fee is not split between revenue recipients
+    const [revenueSplitPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('revenue_split'), providerPda.toBytes()],
+      program.programId,
+    )
+
+    try {
+      await program.methods
+        .createRevenueSplit({
+          recipients: [{ recipient: providerOwner.publicKey, share: 10000 }],
+        })
+        .accounts({
+          revenueSplit: revenueSplitPda,
+          provider: providerPda,
+          delegate: null,
+          authority: providerOwner.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([providerOwner])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'InvalidRefundPolicy')
+    }
+
+    const data = 'refund_user_id'
+    const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+    const [identityPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('bindie', 'utf-8'),
+        Buffer.from(timestamp + '', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const [linkPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('link', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const [escrowPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('escrow'), identityPda.toBytes()],
+      program.programId,
+    )
+
+    const connection = program.provider.connection
+    const providerVaultPda = findVaultPda(providerPda)
+    const vaultBalance = await connection.getBalance(providerVaultPda)
+
+    await program.methods
+      .createBindie({
+        data,
+        encryptionCount: 0,
+        registrationFee: new BN(1000),
+        maxFee: null,
+        integratorShare: null,
+        reveal: null,
+        allowlistProof: null,
+        couponCode: null,
+        timestamp,
+      })
+      .accounts({
+        global: globalPda,
+        bindie: identityPda,
+        link: linkPda,
+        owner: user.publicKey,
+        provider: providerPda,
+        providerVault: providerVaultPda,
+        signer: validatorSigner.publicKey,
+        vault: findVaultPda(globalPda),
+        validator: validatorPda,
+        ownerTokenAccount: null,
+        vaultTokenAccount: null,
+        ownerProviderTokenAccount: null,
+        providerVaultTokenAccount: null,
+        tokenProgram: null,
+        pricingRules: null,
+        coupon: null,
+        holderBindie: null,
+        holderLink: null,
+        previousBindie: null,
+        revenueSplit: null,
+        integrator: null,
+        integratorTokenAccount: null,
+        accessList: null,
+        walletEntry: null,
+        prerequisites: null,
+        license: null,
+        licenseTreasury: null,
+        licenseTreasuryTokenAccount: null,
+        escrow: escrowPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user, validatorSigner])
+      .rpc()
+
+    const escrow = await program.account.feeEscrow.fetch(escrowPda)
+    assert.ok(escrow.amount.eqn(1000))
+
+    await program.methods
+      .voidBindie({
+        data: null,
+      })
+      .accounts({
+        global: globalPda,
+        bindie: identityPda,
+        link: linkPda,
+        provider: providerPda,
+        signer: user.publicKey,
+        vault: findVaultPda(globalPda),
+        validator: validatorPda,
+        validatorSigner: validatorSigner.publicKey,
+        license: null,
+        signerTokenAccount: null,
+        vaultTokenAccount: null,
+        tokenProgram: null,
+        escrow: escrowPda,
+        providerVault: providerVaultPda,
+        bindieOwner: user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user, validatorSigner])
+      .rpc()
+
+    assert.isNull(await program.account.feeEscrow.fetchNullable(escrowPda))
+    assert.strictEqual(
+      await connection.getBalance(providerVaultPda),
+      vaultBalance + 500,
+    )
+
+    await updateRefundPolicy(0, 0)
+  })
+
+  it('should collect the subscription fee once the bindie is due', async () => {
+    const updateSubscriptionPeriod = (subscriptionPeriod: number) =>
+      program.methods
+        .updateProvider({
+          published: null,
+          registrationFee: null,
+          treasury: null,
+          paymentMint: null,
+          minCustomFee: null,
+          maxCustomFee: null,
+          refundWindow: null,
+          refundShare: null,
+          subscriptionPeriod: new BN(subscriptionPeriod),
+          dataSchema: null,
+          maxCreations: null,
+          windowSlots: null,
+          guardian: null,
+          forSale: null,
+          sellingPrice: null,
+        })
+        .accounts({
+          authority: providerOwner.publicKey,
+          delegate: null,
+          provider: providerPda,
+          paymentMint: null,
+        })
+        .signers([providerOwner])
+        .rpc()
+
+    await updateSubscriptionPeriod(1)
+
+    const data = 'subscription_user_id'
+    const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+    const [identityPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('bindie', 'utf-8'),
+        Buffer.from(timestamp + '', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const [linkPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('link', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const [subscriptionPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('subscription'), identityPda.toBytes()],
+      program.programId,
+    )
+
+    await program.methods
+      .createBindie({
+        data,
+        encryptionCount: 0,
+        registrationFee: null,
+        maxFee: null,
+        integratorShare: null,
+        reveal: null,
+        allowlistProof: null,
+        couponCode: null,
+        timestamp,
+      })
+      .accounts({
+        global: globalPda,
+        bindie: identityPda,
+        link: linkPda,
+        owner: user.publicKey,
+        provider: providerPda,
+        providerVault: findVaultPda(providerPda),
+        signer: validatorSigner.publicKey,
+        vault: findVaultPda(globalPda),
+        validator: validatorPda,
+        ownerTokenAccount: null,
+        vaultTokenAccount: null,
+        ownerProviderTokenAccount: null,
+        providerVaultTokenAccount: null,
+        tokenProgram: null,
+        pricingRules: null,
+        coupon: null,
+        holderBindie: null,
+        holderLink: null,
+        previousBindie: null,
+        revenueSplit: null,
+        integrator: null,
+        integratorTokenAccount: null,
+        accessList: null,
+        walletEntry: null,
+        prerequisites: null,
+        license: null,
+        licenseTreasury: null,
+        licenseTreasuryTokenAccount: null,
+        escrow: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user, validatorSigner])
+      .rpc()
+
+    await program.methods
+      .createSubscription({
+        deposit: new BN(LAMPORTS_PER_SOL / 100),
+      })
+      .accounts({
+        subscription: subscriptionPda,
+        bindie: identityPda,
+        provider: providerPda,
+        owner: user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc()
+
+    const bindie = await program.account.bindie.fetch(identityPda)
+    assert.ok(bindie.paidUntil.gtn(0))
+
+    // wait for the period to pass
+    await new Promise((resolve) => setTimeout(resolve, 3000))
+
+    await program.methods
+      .collectSubscription()
+      .accounts({
+        subscription: subscriptionPda,
+        bindie: identityPda,
+        link: linkPda,
+        provider: providerPda,
+        providerVault: findVaultPda(providerPda),
+        revenueSplit: null,
+      })
+      .rpc()
+
+    const renewed = await program.account.bindie.fetch(identityPda)
+    assert.ok(renewed.paidUntil.gt(bindie.paidUntil))
+
+    const voidLapsedBindie = () =>
+      program.methods
+        .voidLapsedBindie()
+        .accounts({
+          bindie: identityPda,
+          link: linkPda,
+          subscription: subscriptionPda,
+          provider: providerPda,
+          owner: user.publicKey,
+        })
+        .rpc()
+
+    await new Promise((resolve) => setTimeout(resolve, 3000))
+
+    // the subscription keeps its own period once the provider stops charging one
+    await updateSubscriptionPeriod(0)
+
+    try {
+      await voidLapsedBindie()
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'SubscriptionNotLapsed')
+    }
+
+    await program.methods
+      .cancelSubscription()
+      .accounts({
+        subscription: subscriptionPda,
+        owner: user.publicKey,
+      })
+      .signers([user])
+      .rpc()
+
+    // once the subscription can no longer pay, anyone can void the bindie
+    await voidLapsedBindie()
+
+    const link = await program.provider.connection.getAccountInfo(linkPda)
+    assert.isNull(link)
+  })
+
+  it('should pay the licensee and the parent share for a licensed bindie', async () => {
+    const connection = program.provider.connection
+    const licensee = Keypair.generate()
+    const licenseSigner = Keypair.generate()
+
+    await airdrop(connection, licensee.publicKey)
+
+    const [licensePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('license'),
+        providerPda.toBytes(),
+        licensee.publicKey.toBytes(),
+      ],
+      program.programId,
+    )
+
+    const [licenseValidatorPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('validator'),
+        providerPda.toBytes(),
+        licenseSigner.publicKey.toBytes(),
+      ],
+      program.programId,
+    )
+
+    await program.methods
+      .grantLicense({
+        licensee: licensee.publicKey,
+        treasury: licensee.publicKey,
+        registrationFee: new BN(1000),
+        parentShare: 2000,
+        expiresAt: new BN(Math.floor(new Date().getTime() / 1000) + 3600),
+      })
+      .accounts({
+        license: licensePda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    await program.methods
+      .createLicenseValidator({
+        enabled: true,
+        signer: licenseSigner.publicKey,
+      })
+      .accounts({
+        validator: licenseValidatorPda,
+        license: licensePda,
+        provider: providerPda,
+        licensee: licensee.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([licensee])
+      .rpc()
+
+    const createLicensedBindie = (data: string) => {
+      const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+      const [identityPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('bindie', 'utf-8'),
+          Buffer.from(timestamp + '', 'utf-8'),
+          providerPda.toBytes(),
+          Buffer.from(data, 'utf-8'),
+        ],
+        program.programId,
+      )
+
+      const [linkPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('link', 'utf-8'),
+          providerPda.toBytes(),
+          Buffer.from(data, 'utf-8'),
+        ],
+        program.programId,
+      )
+
+      return program.methods
+        .createBindie({
+          data,
+          encryptionCount: 0,
+          registrationFee: null,
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
+          timestamp,
+        })
+        .accounts({
+          global: globalPda,
+          bindie: identityPda,
+          link: linkPda,
+          owner: user.publicKey,
+          provider: providerPda,
+          providerVault: findVaultPda(providerPda),
+          signer: licenseSigner.publicKey,
+          vault: findVaultPda(globalPda),
+          validator: licenseValidatorPda,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: null,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: null,
+          walletEntry: null,
+          prerequisites: null,
+          license: licensePda,
+          licenseTreasury: licensee.publicKey,
+          licenseTreasuryTokenAccount: null,
+          escrow: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user, licenseSigner])
+        .rpc()
+    }
+
+    const vaultBalance = await connection.getBalance(findVaultPda(providerPda))
+    const licenseeBalance = await connection.getBalance(licensee.publicKey)
+
+    await createLicensedBindie('licensed_user_id')
+
+    assert.strictEqual(
+      await connection.getBalance(findVaultPda(providerPda)),
+      vaultBalance + 200,
+    )
+    assert.strictEqual(
+      await connection.getBalance(licensee.publicKey),
+      licenseeBalance + 800,
+    )
+
+    // licensees cannot permit their validators to void bindies
+    await program.methods
+      .updateLicenseValidator({
+        close: null,
+        flags: 7,
+        maxCreations: null,
+        windowSlots: null,
+      })
+      .accounts({
+        validator: licenseValidatorPda,
+        license: licensePda,
+        provider: providerPda,
+        licensee: licensee.publicKey,
+      })
+      .signers([licensee])
+      .rpc()
+
+    assert.strictEqual(
+      (await program.account.validator.fetch(licenseValidatorPda)).flags,
+      3,
+    )
+
+    const revokeLicense = () =>
+      program.methods
+        .revokeLicense()
+        .accounts({
+          license: licensePda,
+          provider: providerPda,
+          delegate: null,
+          authority: providerOwner.publicKey,
+        })
+        .signers([providerOwner])
+        .rpc()
+
+    // the license is revoked right away, but only closed once its validators are
+    await revokeLicense()
+
+    try {
+      await createLicensedBindie('revoked_user_id')
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'InvalidLicense')
+    }
+
+    // anyone can close the validators of a revoked license
+    await program.methods
+      .closeLicenseValidators()
+      .accounts({
+        license: licensePda,
+        licensee: licensee.publicKey,
+      })
+      .remainingAccounts([
+        { pubkey: licenseValidatorPda, isWritable: true, isSigner: false },
+      ])
+      .rpc()
+
+    assert.isNull(
+      await program.account.validator.fetchNullable(licenseValidatorPda),
+    )
+
+    await revokeLicense()
+
+    assert.isNull(await program.account.license.fetchNullable(licensePda))
+
+    try {
+      await createLicensedBindie('revoked_user_id')
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'AccountNotInitialized')
+    }
+  })
+
+  it('should reject data which does not match the data schema', async () => {
+    const updateDataSchema = (kind: number, pattern = '') =>
+      program.methods
+        .updateProvider({
+          published: null,
+          registrationFee: null,
+          treasury: null,
+          paymentMint: null,
+          minCustomFee: null,
+          maxCustomFee: null,
+          refundWindow: null,
+          refundShare: null,
+          subscriptionPeriod: null,
+          dataSchema: {
+            kind,
+            length: 0,
+            pattern: [...Buffer.from(pattern.padEnd(32, '\0'))],
+          },
+          maxCreations: null,
+          windowSlots: null,
+          guardian: null,
+          forSale: null,
+          sellingPrice: null,
+        })
+        .accounts({
+          authority: providerOwner.publicKey,
+          delegate: null,
+          provider: providerPda,
+          paymentMint: null,
+        })
+        .signers([providerOwner])
+        .rpc()
+
+    const expectMismatch = async (data: string) => {
+      const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+      const [identityPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('bindie', 'utf-8'),
+          Buffer.from(timestamp + '', 'utf-8'),
+          providerPda.toBytes(),
+          Buffer.from(data, 'utf-8'),
+        ],
+        program.programId,
+      )
+
+      const [linkPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('link', 'utf-8'),
+          providerPda.toBytes(),
+          Buffer.from(data, 'utf-8'),
+        ],
+        program.programId,
+      )
+
+      try {
+        await program.methods
+          .createBindie({
+            data,
+            encryptionCount: 0,
+            registrationFee: null,
+            maxFee: null,
+            integratorShare: null,
+            reveal: null,
+            allowlistProof: null,
+            couponCode: null,
+            timestamp,
+          })
+          .accounts({
+            global: globalPda,
+            bindie: identityPda,
+            link: linkPda,
+            owner: user.publicKey,
+            provider: providerPda,
+            providerVault: findVaultPda(providerPda),
+            signer: validatorSigner.publicKey,
+            vault: findVaultPda(globalPda),
+            validator: validatorPda,
+            ownerTokenAccount: null,
+            vaultTokenAccount: null,
+            ownerProviderTokenAccount: null,
+            providerVaultTokenAccount: null,
+            tokenProgram: null,
+            pricingRules: null,
+            coupon: null,
+            holderBindie: null,
+            holderLink: null,
+            previousBindie: null,
+            revenueSplit: null,
+            integrator: null,
+            integratorTokenAccount: null,
+            accessList: null,
+            walletEntry: null,
+            prerequisites: null,
+            license: null,
+            licenseTreasury: null,
+            licenseTreasuryTokenAccount: null,
+            escrow: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user, validatorSigner])
+          .rpc()
+
+        assert.ok(false)
+      } catch (err) {
+        const e = err as AnchorError
+        assert.strictEqual(e.error.errorCode.code, 'DataSchemaMismatch')
+      }
+    }
+
+    // E.164 phone number
+    await updateDataSchema(1)
+    await expectMismatch('not_a_phone_number')
+
+    // nested quantifiers are matched without backtracking
+    await updateDataSchema(4, 'a*a*a*a*a*a*a*a*a*a*b')
+    await expectMismatch('a'.repeat(32))
+
+    await updateDataSchema(0)
+  })
+
+  it('should require a live bindie of every prerequisite provider', async () => {
+    const [prerequisitesPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('prerequisites'), providerPda.toBytes()],
+      program.programId,
+    )
+
+    await program.methods
+      .createPrerequisites({
+        providers: [Keypair.generate().publicKey],
+      })
+      .accounts({
+        prerequisites: prerequisitesPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const data = 'prerequisite_user_id'
+    const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+    const [identityPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('bindie', 'utf-8'),
+        Buffer.from(timestamp + '', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const [linkPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('link', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    try {
+      await program.methods
+        .createBindie({
+          data,
+          encryptionCount: 0,
+          registrationFee: null,
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
+          timestamp,
+        })
+        .accounts({
+          global: globalPda,
+          bindie: identityPda,
+          link: linkPda,
+          owner: user.publicKey,
+          provider: providerPda,
+          providerVault: findVaultPda(providerPda),
+          signer: validatorSigner.publicKey,
+          vault: findVaultPda(globalPda),
+          validator: validatorPda,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: null,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: null,
+          walletEntry: null,
+          prerequisites: prerequisitesPda,
+          license: null,
+          licenseTreasury: null,
+          licenseTreasuryTokenAccount: null,
+          escrow: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user, validatorSigner])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'MissingPrerequisite')
+    }
+
+    await program.methods
+      .removePrerequisites()
+      .accounts({
+        prerequisites: prerequisitesPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const provider = await program.account.provider.fetch(providerPda)
+    assert.strictEqual(provider.flags & 128, 0)
+  })
+
+  it('should reject a denied wallet', async () => {
+    const [accessListPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('access_list'), providerPda.toBytes()],
+      program.programId,
+    )
+
+    const [walletEntryPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('wallet_entry'),
+        providerPda.toBytes(),
+        user.publicKey.toBytes(),
+      ],
+      program.programId,
+    )
+
+    await program.methods
+      .createAccessList({
+        allowlistRoot: new Array(32).fill(0),
+      })
+      .accounts({
+        accessList: accessListPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    await program.methods
+      .createWalletEntry({
+        wallet: user.publicKey,
+        allowed: false,
+      })
+      .accounts({
+        walletEntry: walletEntryPda,
+        accessList: accessListPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const data = 'denied_user_id'
+    const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+    const [identityPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('bindie', 'utf-8'),
+        Buffer.from(timestamp + '', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const [linkPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('link', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    try {
+      await program.methods
+        .createBindie({
+          data,
+          encryptionCount: 0,
+          registrationFee: null,
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
+          timestamp,
+        })
+        .accounts({
+          global: globalPda,
+          bindie: identityPda,
+          link: linkPda,
+          owner: user.publicKey,
+          provider: providerPda,
+          providerVault: findVaultPda(providerPda),
+          signer: validatorSigner.publicKey,
+          vault: findVaultPda(globalPda),
+          validator: validatorPda,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: null,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: accessListPda,
+          walletEntry: walletEntryPda,
+          prerequisites: null,
+          license: null,
+          licenseTreasury: null,
+          licenseTreasuryTokenAccount: null,
+          escrow: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user, validatorSigner])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'WalletNotAllowed')
+    }
+
+    await program.methods
+      .removeWalletEntry()
+      .accounts({
+        walletEntry: walletEntryPda,
+        accessList: accessListPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    await program.methods
+      .removeAccessList()
+      .accounts({
+        accessList: accessListPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const provider = await program.account.provider.fetch(providerPda)
+    assert.strictEqual(provider.flags & 256, 0)
+  })
+
+  it('should register an allowlisted wallet only with a valid merkle proof', async () => {
+    const [accessListPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('access_list'), providerPda.toBytes()],
+      program.programId,
+    )
+
+    const [walletEntryPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('wallet_entry'),
+        providerPda.toBytes(),
+        user.publicKey.toBytes(),
+      ],
+      program.programId,
+    )
+
+    // the same sorted pair sha256 tree as `AccessList::is_in_allowlist`
+    const sha256 = (...nodes: Buffer[]) =>
+      createHash('sha256').update(Buffer.concat(nodes)).digest()
+    const hashPair = (a: Buffer, b: Buffer) =>
+      Buffer.compare(a, b) <= 0 ? sha256(a, b) : sha256(b, a)
+
+    const [userLeaf, ...otherLeaves] = [
+      user.publicKey,
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+    ].map((wallet) => sha256(wallet.toBuffer()))
+
+    const userPair = hashPair(userLeaf, otherLeaves[0])
+    const otherPair = hashPair(otherLeaves[1], otherLeaves[2])
+    const allowlistRoot = hashPair(userPair, otherPair)
+
+    const validProof = [otherLeaves[0], otherPair]
+    const wrongProof = [otherLeaves[1], userPair]
+
+    await program.methods
+      .createAccessList({
+        allowlistRoot: [...allowlistRoot],
+      })
+      .accounts({
+        accessList: accessListPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const data = 'allowlisted_user_id'
+
+    const createBindie = (allowlistProof: Buffer[] | null) => {
+      const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+      const [identityPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('bindie', 'utf-8'),
+          Buffer.from(timestamp + '', 'utf-8'),
+          providerPda.toBytes(),
+          Buffer.from(data, 'utf-8'),
+        ],
+        program.programId,
+      )
+
+      const [linkPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('link', 'utf-8'),
+          providerPda.toBytes(),
+          Buffer.from(data, 'utf-8'),
+        ],
+        program.programId,
+      )
+
+      return program.methods
+        .createBindie({
+          data,
+          encryptionCount: 0,
+          registrationFee: null,
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: allowlistProof
+            ? allowlistProof.map((node) => [...node])
+            : null,
+          couponCode: null,
+          timestamp,
+        })
+        .accounts({
+          global: globalPda,
+          bindie: identityPda,
+          link: linkPda,
+          owner: user.publicKey,
+          provider: providerPda,
+          providerVault: findVaultPda(providerPda),
+          signer: validatorSigner.publicKey,
+          vault: findVaultPda(globalPda),
+          validator: validatorPda,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: null,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: accessListPda,
+          walletEntry: walletEntryPda,
+          prerequisites: null,
+          license: null,
+          licenseTreasury: null,
+          licenseTreasuryTokenAccount: null,
+          escrow: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user, validatorSigner])
+        .rpc()
+    }
+
+    for (const allowlistProof of [null, wrongProof]) {
+      try {
+        await createBindie(allowlistProof)
+
+        assert.ok(false)
+      } catch (err) {
+        const e = err as AnchorError
+        assert.strictEqual(e.error.errorCode.code, 'WalletNotAllowed')
+      }
+    }
+
+    await createBindie(validProof)
+
+    const [linkPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('link', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const link = await program.account.link.fetch(linkPda)
+    const bindie = await program.account.bindie.fetch(link.bindie)
+    assert.ok(bindie.owner.equals(user.publicKey))
+
+    await program.methods
+      .removeAccessList()
+      .accounts({
+        accessList: accessListPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+      })
+      .signers([providerOwner])
+      .rpc()
+  })
+
+  it('should stop a validator once its rate limit is reached', async () => {
+    const updateRateLimit = (maxCreations: number) =>
+      program.methods
+        .updateValidator({
+          flags: null,
+          close: null,
+          maxCreations,
+          windowSlots: new BN(1_000_000),
+        })
+        .accounts({
+          authority: providerOwner.publicKey,
+          delegate: null,
+          provider: providerPda,
+          validator: validatorPda,
+        })
+        .signers([providerOwner])
+        .rpc()
+
+    const createBindie = (data: string) => {
+      const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+      const [identityPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('bindie', 'utf-8'),
+          Buffer.from(timestamp + '', 'utf-8'),
+          providerPda.toBytes(),
+          Buffer.from(data, 'utf-8'),
+        ],
+        program.programId,
+      )
+
+      const [linkPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('link', 'utf-8'),
+          providerPda.toBytes(),
+          Buffer.from(data, 'utf-8'),
+        ],
+        program.programId,
+      )
+
+      return program.methods
+        .createBindie({
+          data,
+          encryptionCount: 0,
+          registrationFee: null,
+          maxFee: null,
+          integratorShare: null,
+          reveal: null,
+          allowlistProof: null,
+          couponCode: null,
+          timestamp,
+        })
+        .accounts({
+          global: globalPda,
+          bindie: identityPda,
+          link: linkPda,
+          owner: user.publicKey,
+          provider: providerPda,
+          providerVault: findVaultPda(providerPda),
+          signer: validatorSigner.publicKey,
+          vault: findVaultPda(globalPda),
+          validator: validatorPda,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          ownerProviderTokenAccount: null,
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          pricingRules: null,
+          coupon: null,
+          holderBindie: null,
+          holderLink: null,
+          previousBindie: null,
+          revenueSplit: null,
+          integrator: null,
+          integratorTokenAccount: null,
+          accessList: null,
+          walletEntry: null,
+          prerequisites: null,
+          license: null,
+          licenseTreasury: null,
+          licenseTreasuryTokenAccount: null,
+          escrow: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user, validatorSigner])
+        .rpc()
+    }
+
+    await updateRateLimit(1)
+    await createBindie('rate_limited_user_id')
+
+    try {
+      await createBindie('rate_limited_user_id_2')
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'RateLimitExceeded')
+    }
+
+    await updateRateLimit(0)
+  })
+
+  it('should allow a validator manager to update validators only', async () => {
+    const operator = Keypair.generate()
+
+    const [delegatePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('provider_delegate'),
+        providerPda.toBytes(),
+        operator.publicKey.toBytes(),
+      ],
+      program.programId,
+    )
+
+    await program.methods
+      .createProviderDelegate({
+        delegate: operator.publicKey,
+        // validator manager
+        roles: 2,
+      })
+      .accounts({
+        authority: providerOwner.publicKey,
+        provider: providerPda,
+        providerDelegate: delegatePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const validator = await program.account.validator.fetch(validatorPda)
+
+    await program.methods
+      .updateValidator({
+        flags: validator.flags,
+        close: null,
+        maxCreations: null,
+        windowSlots: null,
+      })
+      .accounts({
+        authority: operator.publicKey,
+        delegate: delegatePda,
+        provider: providerPda,
+        validator: validatorPda,
+      })
+      .signers([operator])
+      .rpc()
+
+    try {
+      await program.methods
+        .updateProvider({
+          forSale: null,
+          published: null,
+          sellingPrice: new BN(LAMPORTS_PER_SOL),
+          registrationFee: null,
+          treasury: null,
+          paymentMint: null,
+          minCustomFee: null,
+          maxCustomFee: null,
+          refundWindow: null,
+          refundShare: null,
+          subscriptionPeriod: null,
+          dataSchema: null,
+          maxCreations: null,
+          windowSlots: null,
+          guardian: null,
+        })
+        .accounts({
+          authority: operator.publicKey,
+          delegate: delegatePda,
+          provider: providerPda,
+          paymentMint: null,
+        })
+        .signers([operator])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'RoleUnauthorized')
+    }
+  })
+
+  it('should only let the pending authority accept the provider', async () => {
+    await program.methods
+      .proposeProviderAuthority({
+        pendingAuthority: user.publicKey,
+      })
+      .accounts({
+        authority: providerOwner.publicKey,
+        provider: providerPda,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    try {
+      await program.methods
+        .acceptProviderAuthority()
+        .accounts({
+          pendingAuthority: providerOwner.publicKey,
+          provider: providerPda,
+        })
+        .signers([providerOwner])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'NotPendingAuthority')
+    }
+
+    await program.methods
+      .cancelProviderAuthority()
+      .accounts({
+        authority: providerOwner.publicKey,
+        provider: providerPda,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const provider = await program.account.provider.fetch(providerPda)
+
+    assert.ok(provider.pendingAuthority.equals(PublicKey.default))
+    assert.ok(provider.authority.equals(providerOwner.publicKey))
+  })
+
+  it('should revoke the roles of delegates once the authority changes', async () => {
+    const operator = Keypair.generate()
+
+    const [delegatePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('provider_delegate'),
+        providerPda.toBytes(),
+        operator.publicKey.toBytes(),
+      ],
+      program.programId,
+    )
+
+    const transferProvider = async (from: Keypair, to: Keypair) => {
+      await program.methods
+        .proposeProviderAuthority({
+          pendingAuthority: to.publicKey,
+        })
+        .accounts({
+          authority: from.publicKey,
+          provider: providerPda,
+        })
+        .signers([from])
+        .rpc()
+
+      await program.methods
+        .acceptProviderAuthority()
+        .accounts({
+          pendingAuthority: to.publicKey,
+          provider: providerPda,
+        })
+        .signers([to])
+        .rpc()
+    }
+
+    const updateValidator = async () => {
+      const validator = await program.account.validator.fetch(validatorPda)
+
+      await program.methods
+        .updateValidator({
+          flags: validator.flags,
+          close: null,
+          maxCreations: null,
+          windowSlots: null,
+        })
+        .accounts({
+          authority: operator.publicKey,
+          delegate: delegatePda,
+          provider: providerPda,
+          validator: validatorPda,
+        })
+        .signers([operator])
+        .rpc()
+    }
+
+    await program.methods
+      .createProviderDelegate({
+        delegate: operator.publicKey,
+        // validator manager
+        roles: 2,
+      })
+      .accounts({
+        authority: providerOwner.publicKey,
+        provider: providerPda,
+        providerDelegate: delegatePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    await updateValidator()
+
+    // a round trip through another authority still revokes the roles
+    await transferProvider(providerOwner, user)
+    await transferProvider(user, providerOwner)
+
+    try {
+      await updateValidator()
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'RoleUnauthorized')
+    }
+
+    // granting the roles again restores them
+    await program.methods
+      .updateProviderDelegate({
+        close: null,
+        roles: 2,
+      })
+      .accounts({
+        providerDelegate: delegatePda,
+        provider: providerPda,
+        authority: providerOwner.publicKey,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    await updateValidator()
+  })
+
+  it('should not migrate an account which is already versioned', async () => {
+    const provider = await program.account.provider.fetch(providerPda)
+    assert.strictEqual(provider.version, 1)
+
+    try {
+      await program.methods
+        .migrateProvider()
+        .accounts({
+          provider: providerPda,
+          payer: providerOwner.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([providerOwner])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'InvalidMigration')
+    }
+  })
+
+  it('should migrate accounts created before versioning', async function () {
+    const legacyProviderPda = new PublicKey(
+      'Hb2xk1bzqpuG5ucR4cbfNgWkLLbZ2dg4k4vSK2KjcKnx',
+    )
+    const legacyMetadataPda = new PublicKey(
+      '76MiKwnEUSfNpswqhE7DMjLqoELTievZzkVvzsFQvSsU',
+    )
+    const legacyValidatorPda = new PublicKey(
+      'ACMfA3YbV7Az2trqPUC68N1HAq2g4inJZtSFp9uhfjkq',
+    )
+    const legacyBindiePda = new PublicKey(
+      'EKSBqDqWbdfLLXC3koS61CnyRSGzLBb1CJwGSj8tTZ84',
+    )
+    const legacyLinkPda = new PublicKey(
+      'FXpCMkH5iRP4HkTzRYULW4oNcizMExiKDCzNotbPRY9S',
+    )
+    const legacyGlobalPda = new PublicKey(
+      'ARyMD9yZZyEBst6V8DdxbDWC7fmbXH3zbD2c9arkfmeK',
+    )
+
+    // the legacy accounts are loaded from tests/fixtures by the local validator only
+    const legacyProvider = await program.provider.connection.getAccountInfo(
+      legacyProviderPda,
+    )
+
+    if (!legacyProvider) {
+      this.skip()
+    }
+
+    const payer = program.provider.publicKey
+
+    await program.methods
+      .migrateProvider()
+      .accounts({
+        provider: legacyProviderPda,
+        payer,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc()
+
+    const provider = await program.account.provider.fetch(legacyProviderPda)
+    assert.strictEqual(provider.version, 1)
+    assert.strictEqual(provider.name, 'legacy_provider')
+    assert.strictEqual(provider.flags, 3 | 4096)
+    assert.strictEqual(provider.registrationFee.toNumber(), 1000)
+    assert.strictEqual(
+      provider.authority.toBase58(),
+      '8RU732Aw47KCjR4bfDUUJ4A5zKAVGnUEJ28ErGevdrAZ',
+    )
+    assert.ok(provider.maxCustomFee.eq(new BN('18446744073709551615')))
+
+    // providers created before vaults existed get one to collect fees in
+    await program.methods
+      .createProviderVault()
+      .accounts({
+        providerVault: findVaultPda(legacyProviderPda),
+        provider: legacyProviderPda,
+        payer,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc()
+
+    const providerVault = await program.account.feeVault.fetch(
+      findVaultPda(legacyProviderPda),
+    )
+    assert.ok(providerVault.owner.equals(legacyProviderPda))
+
+    await program.methods
+      .migrateProviderMetadata()
+      .accounts({
+        providerMetadata: legacyMetadataPda,
+        payer,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc()
+
+    const metadata = await program.account.providerMetadata.fetch(
+      legacyMetadataPda,
+    )
+    assert.strictEqual(metadata.version, 1)
+    assert.strictEqual(metadata.uri, 'https://example.com/legacy_provider.json')
+    assert.isNull(metadata.contentHash)
+    assert.deepEqual(metadata.tags, [])
+
+    await program.methods
+      .migrateValidator()
+      .accounts({
+        validator: legacyValidatorPda,
+        payer,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc()
+
+    const validator = await program.account.validator.fetch(legacyValidatorPda)
+    assert.strictEqual(validator.version, 1)
+    assert.strictEqual(validator.flags, 1)
+    assert.ok(validator.provider.equals(legacyProviderPda))
+    assert.strictEqual(
+      validator.signer.toBase58(),
+      '6Drue2o6nUPoHvYsHbviy9A2x3kTunz31VBDvvXRXF38',
+    )
+
+    await program.methods
+      .migrateBindie()
+      .accounts({
+        bindie: legacyBindiePda,
+        payer,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc()
+
+    const bindie = await program.account.bindie.fetch(legacyBindiePda)
+    assert.strictEqual(bindie.version, 1)
+    assert.strictEqual(bindie.data, 'legacy_user_id')
+    assert.ok(bindie.provider.equals(legacyProviderPda))
+    assert.strictEqual(bindie.timestamp.toNumber(), 1690000000)
+    assert.strictEqual(bindie.paidUntil.toNumber(), 0)
+
+    await program.methods
+      .migrateLink()
+      .accounts({
+        link: legacyLinkPda,
+        payer,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc()
+
+    const link = await program.account.link.fetch(legacyLinkPda)
+    assert.strictEqual(link.version, 1)
+    assert.ok(link.bindie.equals(legacyBindiePda))
+
+    await program.methods
+      .migrateGlobal()
+      .accounts({
+        global: legacyGlobalPda,
+        payer,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc()
+
+    const global = await program.account.global.fetch(legacyGlobalPda)
+    assert.strictEqual(global.version, 1)
+    assert.strictEqual(global.serviceFee.toNumber(), 5000)
+    assert.strictEqual(global.providerCreationFee.toNumber(), 100000)
+
+    // a global keeps its size, so only its version tells that it has been migrated
+    try {
+      await program.methods
+        .migrateGlobal()
+        .accounts({
+          global: legacyGlobalPda,
+          payer,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'InvalidMigration')
+    }
+  })
+
+  it('should halt validator changes while paused and timelock the unpause', async () => {
+    const pausedOwner = Keypair.generate()
+    const guardian = Keypair.generate()
+    const pausedName = 'paused_' + Math.floor(Math.random() * 100_000_000)
+
+    // provider creation fee is 1 SOL, so we need more than 1 SOL
+    await airdrop(program.provider.connection, pausedOwner.publicKey)
+    await airdrop(program.provider.connection, pausedOwner.publicKey)
+
+    const [pausedPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('provider'), Buffer.from(pausedName, 'utf-8')],
+      program.programId,
+    )
+
+    const [pausedTombstonePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('tombstone'), Buffer.from(pausedName, 'utf-8')],
+      program.programId,
+    )
+
+    await program.methods
+      .createProvider({
+        name: pausedName,
+        registrationFee: new BN(0),
+        providerTreasury: pausedOwner.publicKey,
+      })
+      .accounts({
+        global: globalPda,
+        owner: pausedOwner.publicKey,
+        provider: pausedPda,
+        providerVault: findVaultPda(pausedPda),
+        tombstone: pausedTombstonePda,
+        vault: findVaultPda(globalPda),
+        parent: null,
+        approval: null,
+        approvalPayer: null,
+        ownerTokenAccount: null,
+        vaultTokenAccount: null,
+        tokenProgram: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([pausedOwner])
+      .rpc()
+
+    await program.methods
+      .updateProvider({
+        published: null,
+        registrationFee: null,
+        treasury: null,
+        paymentMint: null,
+        minCustomFee: null,
+        maxCustomFee: null,
+        refundWindow: null,
+        refundShare: null,
+        subscriptionPeriod: null,
+        dataSchema: null,
+        maxCreations: null,
+        windowSlots: null,
+        guardian: guardian.publicKey,
+        forSale: null,
+        sellingPrice: null,
+      })
+      .accounts({
+        authority: pausedOwner.publicKey,
+        delegate: null,
+        provider: pausedPda,
+        paymentMint: null,
+      })
+      .signers([pausedOwner])
+      .rpc()
+
+    const leakedSigner = Keypair.generate()
+    const [leakedValidatorPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('validator'),
+        pausedPda.toBytes(),
+        leakedSigner.publicKey.toBytes(),
+      ],
+      program.programId,
+    )
+
+    await program.methods
+      .createValidator({
+        enabled: true,
+        signer: leakedSigner.publicKey,
+      })
+      .accounts({
+        authority: pausedOwner.publicKey,
+        delegate: null,
+        provider: pausedPda,
+        validator: leakedValidatorPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([pausedOwner])
+      .rpc()
+
+    await program.methods
+      .pauseProvider()
+      .accounts({
+        provider: pausedPda,
+        signer: guardian.publicKey,
+      })
+      .signers([guardian])
+      .rpc()
+
+    // the provider cannot be listed while paused
+    try {
+      await program.methods
+        .updateProvider({
+          published: null,
+          registrationFee: null,
+          treasury: null,
+          paymentMint: null,
+          minCustomFee: null,
+          maxCustomFee: null,
+          refundWindow: null,
+          refundShare: null,
+          subscriptionPeriod: null,
+          dataSchema: null,
+          maxCreations: null,
+          windowSlots: null,
+          guardian: null,
+          forSale: true,
+          sellingPrice: null,
+        })
+        .accounts({
+          authority: pausedOwner.publicKey,
+          delegate: null,
+          provider: pausedPda,
+          paymentMint: null,
+        })
+        .signers([pausedOwner])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'ProviderPaused')
+    }
+
+    // a leaked validator can still be disabled, but not granted anything
+    const updateLeakedValidator = (flags: number) =>
+      program.methods
+        .updateValidator({
+          flags,
+          close: null,
+          maxCreations: null,
+          windowSlots: null,
+        })
+        .accounts({
+          authority: pausedOwner.publicKey,
+          delegate: null,
+          provider: pausedPda,
+          validator: leakedValidatorPda,
+        })
+        .signers([pausedOwner])
+        .rpc()
+
+    try {
+      await updateLeakedValidator(3)
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'ProviderPaused')
+    }
+
+    await updateLeakedValidator(0)
+
+    assert.strictEqual(
+      (await program.account.validator.fetch(leakedValidatorPda)).flags,
+      0,
+    )
+
+    const signer = Keypair.generate()
+    const [pausedValidatorPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('validator'),
+        pausedPda.toBytes(),
+        signer.publicKey.toBytes(),
+      ],
+      program.programId,
+    )
+
+    try {
+      await program.methods
+        .createValidator({
+          enabled: true,
+          signer: signer.publicKey,
+        })
+        .accounts({
+          authority: pausedOwner.publicKey,
+          delegate: null,
+          provider: pausedPda,
+          validator: pausedValidatorPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([pausedOwner])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'ProviderPaused')
+    }
+
+    await program.methods
+      .requestUnpauseProvider()
+      .accounts({
+        provider: pausedPda,
+        global: globalPda,
+        authority: pausedOwner.publicKey,
+      })
+      .signers([pausedOwner])
+      .rpc()
+
+    // a guardian pause does not cancel the pending unpause
+    const { unpauseAt } = await program.account.provider.fetch(pausedPda)
+
+    await program.methods
+      .pauseProvider()
+      .accounts({
+        provider: pausedPda,
+        signer: guardian.publicKey,
+      })
+      .signers([guardian])
+      .rpc()
+
+    assert.ok(
+      (await program.account.provider.fetch(pausedPda)).unpauseAt.eq(unpauseAt),
+    )
+
+    // the authority can remove the guardian while paused
+    await program.methods
+      .updateProvider({
+        published: null,
+        registrationFee: null,
+        treasury: null,
+        paymentMint: null,
+        minCustomFee: null,
+        maxCustomFee: null,
+        refundWindow: null,
+        refundShare: null,
+        subscriptionPeriod: null,
+        dataSchema: null,
+        maxCreations: null,
+        windowSlots: null,
+        guardian: PublicKey.default,
+        forSale: null,
+        sellingPrice: null,
+      })
+      .accounts({
+        authority: pausedOwner.publicKey,
+        delegate: null,
+        provider: pausedPda,
+        paymentMint: null,
+      })
+      .signers([pausedOwner])
+      .rpc()
+
+    assert.ok(
+      (await program.account.provider.fetch(pausedPda)).guardian.equals(
+        PublicKey.default,
+      ),
+    )
+
+    try {
+      await program.methods
+        .unpauseProvider()
+        .accounts({
+          provider: pausedPda,
+          authority: pausedOwner.publicKey,
+        })
+        .signers([pausedOwner])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'UnpauseTimelocked')
+    }
+
+    const provider = await program.account.provider.fetch(pausedPda)
+
+    assert.strictEqual(provider.flags & 512, 512)
+    assert.ok(provider.unpauseAt.gtn(0))
+  })
 })
@@ -0,0 +1,278 @@
+import * as anchor from '@coral-xyz/anchor'
+import { AnchorError, Program } from '@coral-xyz/anchor'
+import {
+  AccountMeta,
+  Keypair,
+  PublicKey,
+  LAMPORTS_PER_SOL,
+  sendAndConfirmTransaction,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { BN } from 'bn.js'
+import { assert } from 'chai'
+import { Bindentity } from '../target/types/bindentity'
+import { airdrop } from '../scripts/utils'
+
+describe('Provider Multisig', () => {
+  anchor.setProvider(anchor.AnchorProvider.env())
+
+  const program = anchor.workspace.Bindentity as Program<Bindentity>
+
+  const [globalPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('global')],
+    program.programId,
+  )
+
+  const findVaultPda = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('vault'), owner.toBytes()],
+      program.programId,
+    )[0]
+
+  const providerOwner = Keypair.generate()
+  const memberA = Keypair.generate()
+  const memberB = Keypair.generate()
+  const createKey = Keypair.generate()
+  const validatorSigner = Keypair.generate()
+  const bindentityName = 'sample_' + Math.floor(Math.random() * 100_000_000)
+
+  const [providerPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('provider'), Buffer.from(bindentityName, 'utf-8')],
+    program.programId,
+  )
+
+  const [tombstonePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('tombstone'), Buffer.from(bindentityName, 'utf-8')],
+    program.programId,
+  )
+
+  const [validatorPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('validator'),
+      providerPda.toBytes(),
+      validatorSigner.publicKey.toBytes(),
+    ],
+    program.programId,
+  )
+
+  const [multisigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('multisig'), createKey.publicKey.toBytes()],
+    program.programId,
+  )
+
+  const [multisigSignerPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('multisig_signer'), multisigPda.toBytes()],
+    program.programId,
+  )
+
+  const findProposalPda = (index: number) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('multisig_proposal'),
+        multisigPda.toBytes(),
+        Buffer.from(index + ''),
+      ],
+      program.programId,
+    )[0]
+
+  // proposes the instruction through member A and returns the accounts needed for execution
+  const propose = async (ix: TransactionInstruction, index: number) => {
+    await program.methods
+      .createMultisigProposal({
+        accounts: ix.keys.map((key) => ({
+          pubkey: key.pubkey,
+          isSigner: key.isSigner,
+          isWritable: key.isWritable,
+        })),
+        data: ix.data,
+      })
+      .accounts({
+        proposal: findProposalPda(index),
+        multisig: multisigPda,
+        proposer: memberA.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([memberA])
+      .rpc()
+
+    return [
+      ...ix.keys.map((key) => ({
+        pubkey: key.pubkey,
+        isSigner: false,
+        isWritable: key.isWritable,
+      })),
+      { pubkey: program.programId, isSigner: false, isWritable: false },
+    ]
+  }
+
+  const approveAndExecute = async (
+    index: number,
+    remainingAccounts: AccountMeta[],
+  ) => {
+    await program.methods
+      .approveMultisigProposal()
+      .accounts({
+        proposal: findProposalPda(index),
+        multisig: multisigPda,
+        member: memberB.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([memberB])
+      .rpc()
+
+    await program.methods
+      .executeMultisigProposal()
+      .accounts({
+        proposal: findProposalPda(index),
+        multisig: multisigPda,
+        multisigSigner: multisigSignerPda,
+        member: memberB.publicKey,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([memberB])
+      .rpc()
+  }
+
+  before(async () => {
+    const connection = program.provider.connection
+
+    // provider creation fee is 1 SOL, so we need more than 1 SOL
+    await airdrop(connection, providerOwner.publicKey)
+    await airdrop(connection, providerOwner.publicKey)
+
+    // members pay for the proposals, the signer pays for the accounts created by the multisig
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: providerOwner.publicKey,
+          toPubkey: memberA.publicKey,
+          lamports: LAMPORTS_PER_SOL / 10,
+        }),
+        SystemProgram.transfer({
+          fromPubkey: providerOwner.publicKey,
+          toPubkey: memberB.publicKey,
+          lamports: LAMPORTS_PER_SOL / 10,
+        }),
+        SystemProgram.transfer({
+          fromPubkey: providerOwner.publicKey,
+          toPubkey: multisigSignerPda,
+          lamports: LAMPORTS_PER_SOL / 10,
+        }),
+      ),
+      [providerOwner],
+    )
+
+    await program.methods
+      .createProvider({
+        name: bindentityName,
+        providerTreasury: providerOwner.publicKey,
+        registrationFee: new BN(0),
+      })
+      .accounts({
+        owner: providerOwner.publicKey,
+        provider: providerPda,
+        providerVault: findVaultPda(providerPda),
+        tombstone: tombstonePda,
+        vault: findVaultPda(globalPda),
+        global: globalPda,
+        parent: null,
+        approval: null,
+        approvalPayer: null,
+        ownerTokenAccount: null,
+        vaultTokenAccount: null,
+        tokenProgram: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+  })
+
+  it('should create a 2 of 2 multisig', async () => {
+    await program.methods
+      .createMultisig({
+        members: [memberA.publicKey, memberB.publicKey],
+        threshold: 2,
+      })
+      .accounts({
+        multisig: multisigPda,
+        multisigSigner: multisigSignerPda,
+        createKey: createKey.publicKey,
+        payer: memberA.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([createKey, memberA])
+      .rpc()
+
+    // hand over the provider to the multisig
+    await program.methods
+      .proposeProviderAuthority({
+        pendingAuthority: multisigSignerPda,
+      })
+      .accounts({
+        authority: providerOwner.publicKey,
+        provider: providerPda,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const ix = await program.methods
+      .acceptProviderAuthority()
+      .accounts({
+        pendingAuthority: multisigSignerPda,
+        provider: providerPda,
+      })
+      .instruction()
+
+    await approveAndExecute(0, await propose(ix, 0))
+
+    const provider = await program.account.provider.fetch(providerPda)
+
+    assert.ok(provider.authority.equals(multisigSignerPda))
+  })
+
+  it('should only add a validator once the threshold is reached', async () => {
+    const ix = await program.methods
+      .createValidator({
+        enabled: true,
+        signer: validatorSigner.publicKey,
+      })
+      .accounts({
+        authority: multisigSignerPda,
+        delegate: null,
+        provider: providerPda,
+        validator: validatorPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+
+    const remainingAccounts = await propose(ix, 1)
+
+    try {
+      await program.methods
+        .executeMultisigProposal()
+        .accounts({
+          proposal: findProposalPda(1),
+          multisig: multisigPda,
+          multisigSigner: multisigSignerPda,
+          member: memberA.publicKey,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([memberA])
+        .rpc()
+
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'NotEnoughApprovals')
+    }
+
+    await approveAndExecute(1, remainingAccounts)
+
+    const validator = await program.account.validator.fetch(validatorPda)
+
+    assert.ok(validator.signer.equals(validatorSigner.publicKey))
+  })
+})
@@ -23,6 +23,12 @@ describe('Provider Trading', () => {
     program.programId,
   )
 
+  const findVaultPda = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('vault'), owner.toBytes()],
+      program.programId,
+    )[0]
+
   const providerOwner = Keypair.generate()
   const validatorSigner = Keypair.generate()
   const user = Keypair.generate()
@@ -33,6 +39,11 @@ describe('Provider Trading', () => {
     program.programId,
   )
 
+  const [tombstonePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('tombstone'), Buffer.from(bindentityName, 'utf-8')],
+    program.programId,
+  )
+
   const [validatorPda] = PublicKey.findProgramAddressSync(
     [
       Buffer.from('validator'),
@@ -42,8 +53,6 @@ describe('Provider Trading', () => {
     program.programId,
   )
 
-  let global
-
   before(async () => {
     const connection = program.provider.connection
 
@@ -69,8 +78,6 @@ describe('Provider Trading', () => {
       throw new Error(e)
     }
 
-    global = await program.account.global.fetch(globalPda)
-
     // create provider
 
     await program.methods
@@ -82,8 +89,16 @@ describe('Provider Trading', () => {
       .accounts({
         owner: providerOwner.publicKey,
         provider: providerPda,
-        treasury: global.treasury,
+        providerVault: findVaultPda(providerPda),
+        tombstone: tombstonePda,
+        vault: findVaultPda(globalPda),
         global: globalPda,
+        parent: null,
+        approval: null,
+        approvalPayer: null,
+        ownerTokenAccount: null,
+        vaultTokenAccount: null,
+        tokenProgram: null,
         systemProgram: SystemProgram.programId,
       })
       .signers([providerOwner])
@@ -102,6 +117,9 @@ describe('Provider Trading', () => {
           seller: providerOwner.publicKey,
           buyer: user.publicKey,
           provider: providerPda,
+          buyerTokenAccount: null,
+          sellerTokenAccount: null,
+          tokenProgram: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([user])
@@ -123,6 +141,7 @@ describe('Provider Trading', () => {
       })
       .accounts({
         authority: providerOwner.publicKey,
+        delegate: null,
         provider: providerPda,
         validator: validatorPda,
         systemProgram: SystemProgram.programId,
@@ -137,13 +156,24 @@ describe('Provider Trading', () => {
           forSale: true,
           published: null,
           sellingPrice: new BN(LAMPORTS_PER_SOL / 1000),
-          authority: null,
           registrationFee: null,
           treasury: null,
+          paymentMint: null,
+          minCustomFee: null,
+          maxCustomFee: null,
+          refundWindow: null,
+          refundShare: null,
+          subscriptionPeriod: null,
+          dataSchema: null,
+          maxCreations: null,
+          windowSlots: null,
+          guardian: null,
         })
         .accounts({
           authority: providerOwner.publicKey,
+          delegate: null,
           provider: providerPda,
+          paymentMint: null,
         })
         .signers([providerOwner])
         .rpc()
@@ -161,9 +191,12 @@ describe('Provider Trading', () => {
       .updateValidator({
         close: true,
         flags: null,
+        maxCreations: null,
+        windowSlots: null,
       })
       .accounts({
         authority: providerOwner.publicKey,
+        delegate: null,
         provider: providerPda,
         validator: validatorPda,
       })
@@ -176,13 +209,24 @@ describe('Provider Trading', () => {
         forSale: true,
         published: null,
         sellingPrice: new BN(LAMPORTS_PER_SOL / 1000),
-        authority: null,
         registrationFee: null,
         treasury: null,
+        paymentMint: null,
+        minCustomFee: null,
+        maxCustomFee: null,
+        refundWindow: null,
+        refundShare: null,
+        subscriptionPeriod: null,
+        dataSchema: null,
+        maxCreations: null,
+        windowSlots: null,
+        guardian: null,
       })
       .accounts({
         authority: providerOwner.publicKey,
+        delegate: null,
         provider: providerPda,
+        paymentMint: null,
       })
       .signers([providerOwner])
       .rpc()
@@ -194,6 +238,26 @@ describe('Provider Trading', () => {
   })
 
   it('should allow the user to buy the listed provider', async () => {
+    const [revenueSplitPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('revenue_split'), providerPda.toBytes()],
+      program.programId,
+    )
+
+    // the revenue split outlives the sale
+    await program.methods
+      .createRevenueSplit({
+        recipients: [{ recipient: providerOwner.publicKey, share: 10000 }],
+      })
+      .accounts({
+        revenueSplit: revenueSplitPda,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
     // buy the provider
     await program.methods
       .buyProvider({
@@ -204,6 +268,9 @@ describe('Provider Trading', () => {
         seller: providerOwner.publicKey,
         buyer: user.publicKey,
         provider: providerPda,
+        buyerTokenAccount: null,
+        sellerTokenAccount: null,
+        tokenProgram: null,
         systemProgram: SystemProgram.programId,
       })
       .signers([user])
@@ -212,5 +279,531 @@ describe('Provider Trading', () => {
     const provider = await program.account.provider.fetch(providerPda)
 
     assert.ok(provider.authority.equals(user.publicKey))
+    assert.strictEqual(provider.flags & 64, 64)
+
+    await program.methods
+      .removeRevenueSplit()
+      .accounts({
+        revenueSplit: revenueSplitPda,
+        provider: providerPda,
+        delegate: null,
+        authority: user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc()
+  })
+
+  it('should allow the new authority to close the provider once its children are closed', async () => {
+    const delegate = Keypair.generate()
+
+    const [delegatePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('provider_delegate'),
+        providerPda.toBytes(),
+        delegate.publicKey.toBytes(),
+      ],
+      program.programId,
+    )
+
+    const closeProvider = () =>
+      program.methods
+        .closeProvider()
+        .accounts({
+          authority: user.publicKey,
+          provider: providerPda,
+          parent: null,
+          providerVault: findVaultPda(providerPda),
+          providerVaultTokenAccount: null,
+          tokenProgram: null,
+          providerMetadata: null,
+          tombstone: tombstonePda,
+          global: globalPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc()
+
+    await program.methods
+      .createProviderDelegate({
+        delegate: delegate.publicKey,
+        roles: 1,
+      })
+      .accounts({
+        providerDelegate: delegatePda,
+        authority: user.publicKey,
+        provider: providerPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc()
+
+    try {
+      await closeProvider()
+      assert.ok(false)
+    } catch (err) {
+      const e = err as AnchorError
+      assert.strictEqual(e.error.errorCode.code, 'ClosingNotAllowed')
+    }
+
+    await program.methods
+      .updateProviderDelegate({
+        close: true,
+        roles: null,
+      })
+      .accounts({
+        providerDelegate: delegatePda,
+        provider: providerPda,
+        authority: user.publicKey,
+      })
+      .signers([user])
+      .rpc()
+
+    await closeProvider()
+
+    const provider = await program.account.provider.fetchNullable(providerPda)
+    const tombstone = await program.account.tombstone.fetch(tombstonePda)
+
+    assert.isNull(provider)
+    assert.ok(tombstone.authority.equals(user.publicKey))
+  })
+
+  it('should re-create a closed provider without any of its previous children', async () => {
+    // provider creation fee is 1 SOL
+    await airdrop(program.provider.connection, user.publicKey)
+    await airdrop(program.provider.connection, user.publicKey)
+
+    await program.methods
+      .createProvider({
+        name: bindentityName,
+        providerTreasury: user.publicKey,
+        registrationFee: new BN(0),
+      })
+      .accounts({
+        owner: user.publicKey,
+        provider: providerPda,
+        providerVault: findVaultPda(providerPda),
+        tombstone: tombstonePda,
+        vault: findVaultPda(globalPda),
+        global: globalPda,
+        parent: null,
+        approval: null,
+        approvalPayer: null,
+        ownerTokenAccount: null,
+        vaultTokenAccount: null,
+        tokenProgram: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc()
+
+    const provider = await program.account.provider.fetch(providerPda)
+    const tombstone = await program.account.tombstone.fetchNullable(tombstonePda)
+
+    assert.ok(provider.authority.equals(user.publicKey))
+    assert.strictEqual(provider.delegateCount, 0)
+    assert.strictEqual(provider.liveCount.toNumber(), 0)
+    assert.isNull(tombstone)
+  })
+
+  describe('Auction', () => {
+    const auctionOwner = Keypair.generate()
+    const firstBidder = Keypair.generate()
+    const secondBidder = Keypair.generate()
+    const auctionName = 'auction_' + Math.floor(Math.random() * 100_000_000)
+
+    const [auctionProviderPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('provider'), Buffer.from(auctionName, 'utf-8')],
+      program.programId,
+    )
+
+    const [auctionPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('auction'), auctionProviderPda.toBytes()],
+      program.programId,
+    )
+
+    before(async () => {
+      const connection = program.provider.connection
+
+      await airdrop(connection, auctionOwner.publicKey)
+      await airdrop(connection, auctionOwner.publicKey)
+      await airdrop(connection, firstBidder.publicKey)
+      await airdrop(connection, secondBidder.publicKey)
+
+      await program.methods
+        .createProvider({
+          name: auctionName,
+          providerTreasury: auctionOwner.publicKey,
+          registrationFee: new BN(0),
+        })
+        .accounts({
+          owner: auctionOwner.publicKey,
+          provider: auctionProviderPda,
+          providerVault: findVaultPda(auctionProviderPda),
+          tombstone: PublicKey.findProgramAddressSync(
+            [Buffer.from('tombstone'), Buffer.from(auctionName, 'utf-8')],
+            program.programId,
+          )[0],
+          vault: findVaultPda(globalPda),
+          global: globalPda,
+          parent: null,
+          approval: null,
+          approvalPayer: null,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([auctionOwner])
+        .rpc()
+
+      // list the provider for sale
+      await program.methods
+        .updateProvider({
+          forSale: true,
+          published: null,
+          sellingPrice: new BN(LAMPORTS_PER_SOL),
+          registrationFee: null,
+          treasury: null,
+          paymentMint: null,
+          minCustomFee: null,
+          maxCustomFee: null,
+          refundWindow: null,
+          refundShare: null,
+          subscriptionPeriod: null,
+          dataSchema: null,
+          maxCreations: null,
+          windowSlots: null,
+          guardian: null,
+        })
+        .accounts({
+          authority: auctionOwner.publicKey,
+          delegate: null,
+          provider: auctionProviderPda,
+          paymentMint: null,
+        })
+        .signers([auctionOwner])
+        .rpc()
+    })
+
+    it('should start an auction for the listed provider', async () => {
+      await program.methods
+        .startAuction({
+          reservePrice: new BN(LAMPORTS_PER_SOL / 100),
+          minIncrement: new BN(LAMPORTS_PER_SOL / 100),
+          duration: new BN(3),
+          extension: new BN(0),
+        })
+        .accounts({
+          auction: auctionPda,
+          provider: auctionProviderPda,
+          delegate: null,
+          authority: auctionOwner.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([auctionOwner])
+        .rpc()
+
+      const provider = await program.account.provider.fetch(auctionProviderPda)
+
+      assert.ok((provider.flags & 1024) === 1024)
+    })
+
+    it('should not allow buying the provider while in auction', async () => {
+      try {
+        await program.methods
+          .buyProvider({
+            providerTreasury: firstBidder.publicKey,
+            registrationFee: new BN(0),
+          })
+          .accounts({
+            seller: auctionOwner.publicKey,
+            buyer: firstBidder.publicKey,
+            provider: auctionProviderPda,
+            buyerTokenAccount: null,
+            sellerTokenAccount: null,
+            tokenProgram: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([firstBidder])
+          .rpc()
+
+        assert.ok(false)
+      } catch (err) {
+        const e = err as AnchorError
+        assert.strictEqual(e.error.errorCode.code, 'SellingNotAllowed')
+      }
+    })
+
+    it('should refund the outbid bidder', async () => {
+      await program.methods
+        .placeBid({ amount: new BN(LAMPORTS_PER_SOL / 100) })
+        .accounts({
+          auction: auctionPda,
+          bidder: firstBidder.publicKey,
+          previousBidder: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([firstBidder])
+        .rpc()
+
+      try {
+        await program.methods
+          .placeBid({ amount: new BN(LAMPORTS_PER_SOL / 100 + 1) })
+          .accounts({
+            auction: auctionPda,
+            bidder: secondBidder.publicKey,
+            previousBidder: firstBidder.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([secondBidder])
+          .rpc()
+
+        assert.ok(false)
+      } catch (err) {
+        const e = err as AnchorError
+        assert.strictEqual(e.error.errorCode.code, 'BidTooLow')
+      }
+
+      const connection = program.provider.connection
+      const balanceBefore = await connection.getBalance(firstBidder.publicKey)
+
+      await program.methods
+        .placeBid({ amount: new BN(LAMPORTS_PER_SOL / 50) })
+        .accounts({
+          auction: auctionPda,
+          bidder: secondBidder.publicKey,
+          previousBidder: firstBidder.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([secondBidder])
+        .rpc()
+
+      const balanceAfter = await connection.getBalance(firstBidder.publicKey)
+
+      assert.strictEqual(balanceAfter - balanceBefore, LAMPORTS_PER_SOL / 100)
+    })
+
+    it('should transfer the provider to the highest bidder', async () => {
+      // wait for the auction to end
+      await new Promise((resolve) => setTimeout(resolve, 4000))
+
+      await program.methods
+        .settleAuction()
+        .accounts({
+          auction: auctionPda,
+          provider: auctionProviderPda,
+          seller: auctionOwner.publicKey,
+        })
+        .rpc()
+
+      const provider = await program.account.provider.fetch(auctionProviderPda)
+      const auction = await program.account.auction.fetchNullable(auctionPda)
+
+      assert.ok(provider.authority.equals(secondBidder.publicKey))
+      assert.strictEqual(provider.flags, 1)
+      assert.isNull(auction)
+    })
+  })
+
+  describe('Offers', () => {
+    const offerOwner = Keypair.generate()
+    const offerer = Keypair.generate()
+    const otherOfferer = Keypair.generate()
+    const offerName = 'offer_' + Math.floor(Math.random() * 100_000_000)
+
+    const [offerProviderPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('provider'), Buffer.from(offerName, 'utf-8')],
+      program.programId,
+    )
+
+    const findOfferPda = (owner: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('offer'), offerProviderPda.toBytes(), owner.toBytes()],
+        program.programId,
+      )[0]
+
+    before(async () => {
+      const connection = program.provider.connection
+
+      await airdrop(connection, offerOwner.publicKey)
+      await airdrop(connection, offerOwner.publicKey)
+      await airdrop(connection, offerer.publicKey)
+      await airdrop(connection, otherOfferer.publicKey)
+
+      // the provider is not listed for sale
+      await program.methods
+        .createProvider({
+          name: offerName,
+          providerTreasury: offerOwner.publicKey,
+          registrationFee: new BN(0),
+        })
+        .accounts({
+          owner: offerOwner.publicKey,
+          provider: offerProviderPda,
+          providerVault: findVaultPda(offerProviderPda),
+          tombstone: PublicKey.findProgramAddressSync(
+            [Buffer.from('tombstone'), Buffer.from(offerName, 'utf-8')],
+            program.programId,
+          )[0],
+          vault: findVaultPda(globalPda),
+          global: globalPda,
+          parent: null,
+          approval: null,
+          approvalPayer: null,
+          ownerTokenAccount: null,
+          vaultTokenAccount: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([offerOwner])
+        .rpc()
+    })
+
+    it('should allow the offerer to cancel and withdraw an offer', async () => {
+      const connection = program.provider.connection
+
+      await program.methods
+        .placeOffer({
+          amount: new BN(LAMPORTS_PER_SOL / 100),
+          providerTreasury: otherOfferer.publicKey,
+          registrationFee: new BN(0),
+        })
+        .accounts({
+          offer: findOfferPda(otherOfferer.publicKey),
+          provider: offerProviderPda,
+          offerer: otherOfferer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([otherOfferer])
+        .rpc()
+
+      const balanceBefore = await connection.getBalance(otherOfferer.publicKey)
+
+      await program.methods
+        .cancelOffer()
+        .accounts({
+          offer: findOfferPda(otherOfferer.publicKey),
+          offerer: otherOfferer.publicKey,
+        })
+        .signers([otherOfferer])
+        .rpc()
+
+      const balanceAfter = await connection.getBalance(otherOfferer.publicKey)
+      const offer = await program.account.offer.fetchNullable(
+        findOfferPda(otherOfferer.publicKey),
+      )
+
+      assert.isNull(offer)
+      assert.ok(balanceAfter - balanceBefore > LAMPORTS_PER_SOL / 100)
+    })
+
+    it('should not accept an offer replaced before the acceptance lands', async () => {
+      const placeOffer = (amount: number) =>
+        program.methods
+          .placeOffer({
+            amount: new BN(amount),
+            providerTreasury: otherOfferer.publicKey,
+            registrationFee: new BN(0),
+          })
+          .accounts({
+            offer: findOfferPda(otherOfferer.publicKey),
+            provider: offerProviderPda,
+            offerer: otherOfferer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([otherOfferer])
+          .rpc()
+
+      const cancelOffer = () =>
+        program.methods
+          .cancelOffer()
+          .accounts({
+            offer: findOfferPda(otherOfferer.publicKey),
+            offerer: otherOfferer.publicKey,
+          })
+          .signers([otherOfferer])
+          .rpc()
+
+      await placeOffer(LAMPORTS_PER_SOL / 100)
+
+      // the offerer swaps the offer for a single lamport
+      await cancelOffer()
+      await placeOffer(1)
+
+      try {
+        await program.methods
+          .acceptOffer({
+            expectedAmount: new BN(LAMPORTS_PER_SOL / 100),
+            expectedTreasury: otherOfferer.publicKey,
+            expectedRegistrationFee: new BN(0),
+          })
+          .accounts({
+            offer: findOfferPda(otherOfferer.publicKey),
+            provider: offerProviderPda,
+            authority: offerOwner.publicKey,
+            offerer: otherOfferer.publicKey,
+          })
+          .signers([offerOwner])
+          .rpc()
+
+        assert.ok(false)
+      } catch (err) {
+        const e = err as AnchorError
+        assert.strictEqual(e.error.errorCode.code, 'InvalidOffer')
+      }
+
+      const provider = await program.account.provider.fetch(offerProviderPda)
+      assert.ok(provider.authority.equals(offerOwner.publicKey))
+
+      await cancelOffer()
+    })
+
+    it('should transfer an unlisted provider to the offerer once accepted', async () => {
+      const connection = program.provider.connection
+
+      await program.methods
+        .placeOffer({
+          amount: new BN(LAMPORTS_PER_SOL / 100),
+          providerTreasury: offerer.publicKey,
+          registrationFee: new BN(0),
+        })
+        .accounts({
+          offer: findOfferPda(offerer.publicKey),
+          provider: offerProviderPda,
+          offerer: offerer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([offerer])
+        .rpc()
+
+      const balanceBefore = await connection.getBalance(offerOwner.publicKey)
+
+      await program.methods
+        .acceptOffer({
+          expectedAmount: new BN(LAMPORTS_PER_SOL / 100),
+          expectedTreasury: offerer.publicKey,
+          expectedRegistrationFee: new BN(0),
+        })
+        .accounts({
+          offer: findOfferPda(offerer.publicKey),
+          provider: offerProviderPda,
+          authority: offerOwner.publicKey,
+          offerer: offerer.publicKey,
+        })
+        .signers([offerOwner])
+        .rpc()
+
+      const balanceAfter = await connection.getBalance(offerOwner.publicKey)
+      const provider = await program.account.provider.fetch(offerProviderPda)
+      const offer = await program.account.offer.fetchNullable(
+        findOfferPda(offerer.publicKey),
+      )
+
+      assert.isNull(offer)
+      assert.ok(provider.authority.equals(offerer.publicKey))
+      assert.ok(provider.treasury.equals(offerer.publicKey))
+      assert.ok(balanceAfter > balanceBefore)
+    })
   })
 })
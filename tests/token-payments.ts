@@ -0,0 +1,460 @@
+import * as anchor from '@coral-xyz/anchor'
+import { Program } from '@coral-xyz/anchor'
+import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js'
+import {
+  TOKEN_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token'
+import { BN } from 'bn.js'
+import { assert } from 'chai'
+import { Bindentity } from '../target/types/bindentity'
+import authorityJSON from '../keys/authority.json'
+import { airdrop } from '../scripts/utils'
+
+describe('Token Payments', () => {
+  anchor.setProvider(anchor.AnchorProvider.env())
+
+  const program = anchor.workspace.Bindentity as Program<Bindentity>
+  const connection = program.provider.connection
+  const authority = Keypair.fromSecretKey(new Uint8Array(authorityJSON))
+
+  const [globalPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('global')],
+    program.programId,
+  )
+
+  const findVaultPda = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('vault'), owner.toBytes()],
+      program.programId,
+    )[0]
+
+  const findEscrowPda = (bindie: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('escrow'), bindie.toBytes()],
+      program.programId,
+    )[0]
+
+  const providerOwner = Keypair.generate()
+  const validatorSigner = Keypair.generate()
+  const user = Keypair.generate()
+  const buyer = Keypair.generate()
+  const bindentityName = 'token_' + Math.floor(Math.random() * 100_000_000)
+
+  const [providerPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('provider'), Buffer.from(bindentityName, 'utf-8')],
+    program.programId,
+  )
+
+  const [tombstonePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('tombstone'), Buffer.from(bindentityName, 'utf-8')],
+    program.programId,
+  )
+
+  const [validatorPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('validator'),
+      providerPda.toBytes(),
+      validatorSigner.publicKey.toBytes(),
+    ],
+    program.programId,
+  )
+
+  const data = 'token_user_id'
+
+  const [linkPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('link', 'utf-8'),
+      providerPda.toBytes(),
+      Buffer.from(data, 'utf-8'),
+    ],
+    program.programId,
+  )
+
+  const providerCreationFee = 1000
+  const serviceFee = 10
+  const registrationFee = 500
+  const sellingPrice = 2000
+
+  let global
+  let mint: PublicKey
+  let vaultAta: PublicKey
+  let providerVaultAta: PublicKey
+  let providerOwnerAta: PublicKey
+  let userAta: PublicKey
+  let buyerAta: PublicKey
+
+  const balanceOf = async (tokenAccount: PublicKey) =>
+    Number((await getAccount(connection, tokenAccount)).amount)
+
+  const updateConfig = (
+    paymentMint: PublicKey,
+    providerCreationFee: BN,
+    serviceFee: BN,
+  ) =>
+    program.methods
+      .updateConfig({
+        providerCreationFee,
+        serviceFee,
+        providerCooldown: null,
+        treasury: null,
+        paymentMint,
+        maxIntegratorShare: null,
+        unpauseTimelock: null,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        global: globalPda,
+        paymentMint: paymentMint.equals(PublicKey.default) ? null : paymentMint,
+      })
+      .signers([authority])
+      .rpc()
+
+  const updateProvider = (params: {
+    published?: boolean
+    paymentMint?: PublicKey
+    registrationFee?: BN
+    forSale?: boolean
+    sellingPrice?: BN
+  }) =>
+    program.methods
+      .updateProvider({
+        published: params.published ?? null,
+        registrationFee: params.registrationFee ?? null,
+        treasury: null,
+        paymentMint: params.paymentMint ?? null,
+        minCustomFee: null,
+        maxCustomFee: null,
+        refundWindow: null,
+        refundShare: null,
+        subscriptionPeriod: null,
+        dataSchema: null,
+        maxCreations: null,
+        windowSlots: null,
+        guardian: null,
+        forSale: params.forSale ?? null,
+        sellingPrice: params.sellingPrice ?? null,
+      })
+      .accounts({
+        authority: providerOwner.publicKey,
+        delegate: null,
+        provider: providerPda,
+        paymentMint: params.paymentMint ?? null,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+  before(async () => {
+    const _global = await program.account.global.fetchNullable(globalPda)
+
+    if (!_global) {
+      throw new Error(
+        'Global config not found, please run `anchor run init` to initialize.',
+      )
+    }
+
+    global = _global
+
+    await airdrop(connection, providerOwner.publicKey)
+    await airdrop(connection, user.publicKey)
+    await airdrop(connection, buyer.publicKey)
+
+    mint = await createMint(
+      connection,
+      providerOwner,
+      providerOwner.publicKey,
+      null,
+      0,
+    )
+
+    const createAta = async (owner: PublicKey, amount = 0) => {
+      const { address } = await getOrCreateAssociatedTokenAccount(
+        connection,
+        providerOwner,
+        mint,
+        owner,
+        true,
+      )
+
+      if (amount > 0) {
+        await mintTo(
+          connection,
+          providerOwner,
+          mint,
+          address,
+          providerOwner,
+          amount,
+        )
+      }
+
+      return address
+    }
+
+    vaultAta = await createAta(findVaultPda(globalPda))
+    providerVaultAta = await createAta(findVaultPda(providerPda))
+    providerOwnerAta = await createAta(providerOwner.publicKey, 10_000)
+    userAta = await createAta(user.publicKey, 10_000)
+    buyerAta = await createAta(buyer.publicKey, 10_000)
+
+    // the protocol fees are paid in the test mint until the suite is done
+    await updateConfig(mint, new BN(providerCreationFee), new BN(serviceFee))
+  })
+
+  after(async () => {
+    await updateConfig(
+      global.paymentMint,
+      global.providerCreationFee,
+      global.serviceFee,
+    )
+  })
+
+  it('should pay the provider creation fee in SPL token', async () => {
+    const vaultBalance = await balanceOf(vaultAta)
+
+    await program.methods
+      .createProvider({
+        name: bindentityName,
+        registrationFee: new BN(0),
+        providerTreasury: providerOwner.publicKey,
+      })
+      .accounts({
+        global: globalPda,
+        owner: providerOwner.publicKey,
+        provider: providerPda,
+        providerVault: findVaultPda(providerPda),
+        tombstone: tombstonePda,
+        vault: findVaultPda(globalPda),
+        parent: null,
+        approval: null,
+        approvalPayer: null,
+        ownerTokenAccount: providerOwnerAta,
+        vaultTokenAccount: vaultAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    assert.strictEqual(
+      await balanceOf(providerOwnerAta),
+      10_000 - providerCreationFee,
+    )
+    assert.strictEqual(
+      await balanceOf(vaultAta),
+      vaultBalance + providerCreationFee,
+    )
+
+    await updateProvider({
+      published: true,
+      paymentMint: mint,
+      registrationFee: new BN(registrationFee),
+    })
+
+    await program.methods
+      .createValidator({
+        enabled: true,
+        signer: validatorSigner.publicKey,
+      })
+      .accounts({
+        authority: providerOwner.publicKey,
+        delegate: null,
+        provider: providerPda,
+        validator: validatorPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    const provider = await program.account.provider.fetch(providerPda)
+
+    assert.ok(provider.paymentMint.equals(mint))
+  })
+
+  it('should pay the service and registration fees of a bindie in SPL token', async () => {
+    const timestamp = new BN(Math.floor(new Date().getTime() / 1000))
+    const [identityPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('bindie', 'utf-8'),
+        Buffer.from(timestamp + '', 'utf-8'),
+        providerPda.toBytes(),
+        Buffer.from(data, 'utf-8'),
+      ],
+      program.programId,
+    )
+
+    const vaultBalance = await balanceOf(vaultAta)
+
+    await program.methods
+      .createBindie({
+        data,
+        encryptionCount: 0,
+        registrationFee: null,
+        maxFee: null,
+        integratorShare: null,
+        reveal: null,
+        allowlistProof: null,
+        couponCode: null,
+        timestamp,
+      })
+      .accounts({
+        global: globalPda,
+        bindie: identityPda,
+        link: linkPda,
+        owner: user.publicKey,
+        provider: providerPda,
+        providerVault: findVaultPda(providerPda),
+        signer: validatorSigner.publicKey,
+        vault: findVaultPda(globalPda),
+        validator: validatorPda,
+        ownerTokenAccount: userAta,
+        vaultTokenAccount: vaultAta,
+        ownerProviderTokenAccount: userAta,
+        providerVaultTokenAccount: providerVaultAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        pricingRules: null,
+        coupon: null,
+        holderBindie: null,
+        holderLink: null,
+        previousBindie: null,
+        revenueSplit: null,
+        integrator: null,
+        integratorTokenAccount: null,
+        accessList: null,
+        walletEntry: null,
+        prerequisites: null,
+        license: null,
+        licenseTreasury: null,
+        licenseTreasuryTokenAccount: null,
+        escrow: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user, validatorSigner])
+      .rpc()
+
+    assert.strictEqual(
+      await balanceOf(userAta),
+      10_000 - serviceFee - registrationFee,
+    )
+    assert.strictEqual(await balanceOf(vaultAta), vaultBalance + serviceFee)
+    assert.strictEqual(await balanceOf(providerVaultAta), registrationFee)
+  })
+
+  it('should withdraw the registration fees from the provider vault token account', async () => {
+    const ownerBalance = await balanceOf(providerOwnerAta)
+
+    await program.methods
+      .withdrawProviderFees({
+        amount: new BN(registrationFee),
+      })
+      .accounts({
+        providerVault: findVaultPda(providerPda),
+        providerTreasury: providerOwner.publicKey,
+        provider: providerPda,
+        delegate: null,
+        authority: providerOwner.publicKey,
+        providerVaultTokenAccount: providerVaultAta,
+        providerTreasuryTokenAccount: providerOwnerAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    assert.strictEqual(await balanceOf(providerVaultAta), 0)
+    assert.strictEqual(
+      await balanceOf(providerOwnerAta),
+      ownerBalance + registrationFee,
+    )
+  })
+
+  it('should pay the service fee of a void in SPL token', async () => {
+    const link = await program.account.link.fetch(linkPda)
+    const userBalance = await balanceOf(userAta)
+    const vaultBalance = await balanceOf(vaultAta)
+
+    await program.methods
+      .voidBindie({
+        data: null,
+      })
+      .accounts({
+        global: globalPda,
+        bindie: link.bindie,
+        link: linkPda,
+        provider: providerPda,
+        signer: user.publicKey,
+        vault: findVaultPda(globalPda),
+        validator: validatorPda,
+        validatorSigner: validatorSigner.publicKey,
+        license: null,
+        signerTokenAccount: userAta,
+        vaultTokenAccount: vaultAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        escrow: findEscrowPda(link.bindie),
+        providerVault: null,
+        bindieOwner: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user, validatorSigner])
+      .rpc()
+
+    assert.strictEqual(await balanceOf(userAta), userBalance - serviceFee)
+    assert.strictEqual(await balanceOf(vaultAta), vaultBalance + serviceFee)
+  })
+
+  it('should sell the provider for SPL token', async () => {
+    // a listed provider has to be unpublished and without validators
+    await program.methods
+      .updateValidator({
+        close: true,
+        flags: null,
+        maxCreations: null,
+        windowSlots: null,
+      })
+      .accounts({
+        authority: providerOwner.publicKey,
+        delegate: null,
+        provider: providerPda,
+        validator: validatorPda,
+      })
+      .signers([providerOwner])
+      .rpc()
+
+    await updateProvider({ published: false })
+    await updateProvider({
+      forSale: true,
+      sellingPrice: new BN(sellingPrice),
+    })
+
+    const sellerBalance = await balanceOf(providerOwnerAta)
+
+    await program.methods
+      .buyProvider({
+        providerTreasury: buyer.publicKey,
+        registrationFee: new BN(0),
+      })
+      .accounts({
+        seller: providerOwner.publicKey,
+        buyer: buyer.publicKey,
+        provider: providerPda,
+        buyerTokenAccount: buyerAta,
+        sellerTokenAccount: providerOwnerAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer])
+      .rpc()
+
+    assert.strictEqual(await balanceOf(buyerAta), 10_000 - sellingPrice)
+    assert.strictEqual(
+      await balanceOf(providerOwnerAta),
+      sellerBalance + sellingPrice,
+    )
+
+    const provider = await program.account.provider.fetch(providerPda)
+
+    assert.ok(provider.authority.equals(buyer.publicKey))
+    assert.ok(provider.paymentMint.equals(PublicKey.default))
+  })
+})